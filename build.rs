@@ -0,0 +1,67 @@
+//! Generates the registry of solutions that the runner binary compiles in with the `solutions`
+//! feature, so that `cargo all` and `cargo time` can run them in-process.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    let mut solutions = vec![];
+
+    if env::var_os("CARGO_FEATURE_SOLUTIONS").is_some() {
+        // NOTE: only watch the solutions with the feature enabled, so that editing a solution
+        // does not rebuild the library for `cargo solve`.
+        println!("cargo::rerun-if-changed=src/bin");
+
+        let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+        if let Ok(entries) = fs::read_dir(&bin_dir) {
+            solutions = entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| is_solution(path))
+                .collect();
+        }
+        solutions.sort();
+    } else {
+        println!("cargo::rerun-if-changed=build.rs");
+    }
+
+    let mut registry = String::new();
+
+    for path in &solutions {
+        let name = path.file_stem().unwrap().to_string_lossy();
+        registry.push_str(&format!(
+            "#[path = {:?}]\nmod solution_{name};\n\n",
+            path.display().to_string()
+        ));
+    }
+
+    registry.push_str("pub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[\n");
+    for path in &solutions {
+        let name = path.file_stem().unwrap().to_string_lossy();
+        registry.push_str(&format!("    solution_{name}::SOLUTION,\n"));
+    }
+    registry.push_str("];\n");
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
+
+/// Solutions are named like `2025_01.rs` and use the `solution!` macro, which registers them.
+fn is_solution(path: &Path) -> bool {
+    let is_named_like_solution = path.extension().is_some_and(|x| x == "rs")
+        && path
+            .file_stem()
+            .and_then(|x| x.to_str())
+            .is_some_and(|stem| {
+                let bytes = stem.as_bytes();
+                bytes.len() == 7
+                    && bytes[4] == b'_'
+                    && bytes
+                        .iter()
+                        .enumerate()
+                        .all(|(i, b)| i == 4 || b.is_ascii_digit())
+            });
+
+    is_named_like_solution
+        && fs::read_to_string(path).is_ok_and(|source| source.contains("solution!("))
+}
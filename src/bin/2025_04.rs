@@ -1,9 +1,6 @@
-use std::{
-    ops::{Deref, DerefMut},
-    str::FromStr,
-};
+use std::{ops::Deref, str::FromStr};
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(4, parse = parse);
 
 const NEIGHBOR_COORDS: [(isize, isize); 8] = [
     (-1, -1),
@@ -17,7 +14,7 @@ const NEIGHBOR_COORDS: [(isize, isize); 8] = [
 ];
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Position {
+pub enum Position {
     Roll,
     Empty,
 }
@@ -31,8 +28,8 @@ impl From<char> for Position {
     }
 }
 
-#[derive(Debug)]
-struct Grid(Vec<Vec<Position>>);
+#[derive(Debug, Clone)]
+pub struct Grid(Vec<Vec<Position>>);
 
 impl Grid {
     pub fn iter_neighbors(
@@ -59,12 +56,6 @@ impl Grid {
                 })
         })
     }
-
-    pub fn remove(&mut self, (x, y): (usize, usize)) {
-        if let Some(position) = self.get_mut(y).and_then(|row| row.get_mut(x)) {
-            *position = Position::Empty;
-        }
-    }
 }
 
 impl Deref for Grid {
@@ -75,12 +66,6 @@ impl Deref for Grid {
     }
 }
 
-impl DerefMut for Grid {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
 impl FromStr for Grid {
     type Err = ();
 
@@ -94,9 +79,11 @@ impl FromStr for Grid {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let grid: Grid = input.parse().unwrap();
+pub fn parse(input: &str) -> Grid {
+    input.parse().unwrap()
+}
 
+pub fn part_one(grid: &Grid) -> Option<u64> {
     let accessible = grid
         .iter_neighbors()
         .filter(|(_, neighbors, _)| {
@@ -111,33 +98,43 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(accessible as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut grid: Grid = input.parse().unwrap();
+pub fn part_two(grid: &Grid) -> Option<u64> {
+    // NOTE: instead of removing rolls from a copy of the grid round by round, count the roll
+    // neighbors of every roll once and update the counts as accessible rolls are removed.
+    let mut counts: Vec<Vec<Option<usize>>> =
+        grid.iter().map(|row| vec![None; row.len()]).collect();
+    let mut accessible_to_remove = vec![];
+
+    for (_, neighbors, (x, y)) in grid.iter_neighbors() {
+        let count = neighbors
+            .iter()
+            .filter(|neighbor| matches!(*neighbor, Position::Roll))
+            .count();
+
+        counts[y][x] = Some(count);
+        if count < 4 {
+            accessible_to_remove.push((x, y));
+        }
+    }
 
     let mut accessible = 0;
 
-    loop {
-        let accessible_before = accessible;
-
-        let accessible_to_remove = grid
-            .iter_neighbors()
-            .filter_map(|(_, neighbors, coords)| {
-                (neighbors
-                    .iter()
-                    .filter(|neighbor| matches!(*neighbor, Position::Roll))
-                    .count()
-                    < 4)
-                .then_some(coords)
-            })
-            .collect::<Vec<_>>();
-
-        for coords in accessible_to_remove {
-            grid.remove(coords);
-            accessible += 1;
-        }
-
-        if accessible == accessible_before {
-            break;
+    while let Some((x, y)) = accessible_to_remove.pop() {
+        counts[y][x] = None;
+        accessible += 1;
+
+        for (xd, yd) in NEIGHBOR_COORDS {
+            let (Some(x), Some(y)) = (x.checked_add_signed(xd), y.checked_add_signed(yd)) else {
+                continue;
+            };
+
+            // NOTE: a roll becomes accessible exactly once, when its count drops below 4.
+            if let Some(Some(count)) = counts.get_mut(y).and_then(|row| row.get_mut(x)) {
+                *count -= 1;
+                if *count == 3 {
+                    accessible_to_remove.push((x, y));
+                }
+            }
         }
     }
 
@@ -150,13 +147,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(43));
     }
 }
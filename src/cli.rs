@@ -0,0 +1,358 @@
+//! The command-line interface, shared by the main binary and the `solutions` runner.
+#[cfg(feature = "today")]
+use advent_of_code::template::commands::today;
+use advent_of_code::template::{
+    commands::{
+        all, answer, checksums, download, leaderboard, read, scaffold, solve, status, time, verify,
+    },
+    runner::Solution,
+};
+use args::{AppArguments, parse};
+
+mod args {
+    use advent_of_code::template::commands::{leaderboard::LeaderboardSource, solve::SolveOptions};
+    use advent_of_code::template::{
+        BenchConfig, Calendar, Day, DaySet, InputSource, Part, PuzzleId, Timeouts, Year,
+    };
+    use std::{num::NonZeroUsize, process};
+
+    pub enum AppArguments {
+        Download {
+            puzzle: PuzzleId,
+            force: bool,
+        },
+        Read {
+            puzzle: PuzzleId,
+        },
+        Answer {
+            puzzle: PuzzleId,
+            source: InputSource,
+            answer: Option<(Part, String)>,
+        },
+        Scaffold {
+            puzzle: PuzzleId,
+            download: bool,
+            overwrite: bool,
+        },
+        Solve {
+            year: Year,
+            days: DaySet,
+            options: SolveOptions,
+        },
+        All {
+            year: Year,
+            days: DaySet,
+            release: bool,
+            timeouts: Timeouts,
+            alloc_stats: bool,
+            jobs: NonZeroUsize,
+        },
+        Time {
+            year: Year,
+            all: bool,
+            days: Option<DaySet>,
+            store: bool,
+            bench: BenchConfig,
+            timeouts: Timeouts,
+            alloc_stats: bool,
+        },
+        Verify {
+            year: Year,
+            days: DaySet,
+        },
+        Checksums {
+            year: Year,
+            days: DaySet,
+        },
+        Status {
+            year: Year,
+            days: DaySet,
+            run: bool,
+        },
+        Leaderboard {
+            source: LeaderboardSource,
+            day: Option<Day>,
+        },
+        #[cfg(feature = "today")]
+        Today {
+            wait: bool,
+        },
+    }
+
+    /// Parses the `--year` option, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        year_or_env(args.opt_value_from_str("--year")?)
+    }
+
+    fn year_or_env(year: Option<Year>) -> Result<Year, Box<dyn std::error::Error>> {
+        match year {
+            Some(year) => Ok(year),
+            None => Year::from_env().ok_or_else(|| {
+                "no year specified. Pass `--year <year>` or set the `AOC_YEAR` environment variable."
+                    .into()
+            }),
+        }
+    }
+
+    /// Parses a day, optionally qualified by the `--year` option.
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        puzzle_of(year, args.free_from_str()?)
+    }
+
+    fn puzzle_of(year: Year, day: Day) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        PuzzleId::new(year, day).ok_or_else(|| {
+            let days = Calendar::of(year).len();
+            format!("day {day} is not part of the {year} event, which has {days} days.").into()
+        })
+    }
+
+    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_env();
+
+        let app_args = match args.subcommand()?.as_deref() {
+            Some("all") => {
+                let year = parse_year(&mut args)?;
+                let release = args.contains("--release");
+                let timeouts = Timeouts::from_args(&mut args)?;
+                let alloc_stats = args.contains("--alloc-stats");
+                let jobs = args
+                    .opt_value_from_str(["-j", "--jobs"])?
+                    .unwrap_or(NonZeroUsize::MIN);
+
+                AppArguments::All {
+                    year,
+                    days: args.opt_free_from_str()?.unwrap_or_else(DaySet::all),
+                    release,
+                    timeouts,
+                    alloc_stats,
+                    jobs,
+                }
+            }
+            Some("time") => {
+                let year = parse_year(&mut args)?;
+                let all = args.contains("--all");
+                let store = args.contains("--store");
+                let bench = BenchConfig::from_args(&mut args)?;
+                let timeouts = Timeouts::from_args(&mut args)?;
+                let alloc_stats = args.contains("--alloc-stats");
+                let days: Option<DaySet> = args.opt_free_from_str()?;
+
+                if all && days.is_some() {
+                    return Err("`--all` runs every day and cannot be combined with days.".into());
+                }
+
+                AppArguments::Time {
+                    year,
+                    all,
+                    days,
+                    store,
+                    bench,
+                    timeouts,
+                    alloc_stats,
+                }
+            }
+            Some("verify") => {
+                let year = parse_year(&mut args)?;
+
+                AppArguments::Verify {
+                    year,
+                    days: args.opt_free_from_str()?.unwrap_or_else(DaySet::all),
+                }
+            }
+            Some("checksums") => {
+                let year = parse_year(&mut args)?;
+
+                AppArguments::Checksums {
+                    year,
+                    days: args.opt_free_from_str()?.unwrap_or_else(DaySet::all),
+                }
+            }
+            Some("status") => {
+                let year = parse_year(&mut args)?;
+                let run = args.contains("--run");
+
+                AppArguments::Status {
+                    year,
+                    days: args.opt_free_from_str()?.unwrap_or_else(DaySet::all),
+                    run,
+                }
+            }
+            Some("leaderboard") => {
+                let year = args.opt_value_from_str("--year")?;
+                let day = args.opt_value_from_str("--day")?;
+                let id = args.opt_value_from_str("--id")?;
+                // NOTE: an export names its year, only downloads need one.
+                let source = match args.opt_free_from_str()? {
+                    Some(_) if year.is_some() => {
+                        return Err(
+                            "`--year` cannot be combined with a file, it names its year.".into(),
+                        );
+                    }
+                    Some(path) => LeaderboardSource::File(path),
+                    None => LeaderboardSource::Download(year_or_env(year)?, id),
+                };
+
+                AppArguments::Leaderboard { source, day }
+            }
+            Some("download") => AppArguments::Download {
+                force: args.contains("--force"),
+                puzzle: parse_puzzle(&mut args)?,
+            },
+            Some("read") => AppArguments::Read {
+                puzzle: parse_puzzle(&mut args)?,
+            },
+            Some("answer") => {
+                let part: Option<Part> = args.opt_value_from_str("--part")?;
+                let source = InputSource::from_args(&mut args)?;
+                if matches!(source, InputSource::File(_)) {
+                    return Err("answers can only be stored for puzzle inputs and examples.".into());
+                }
+                let puzzle = parse_puzzle(&mut args)?;
+
+                let answer = match part {
+                    Some(part) => Some((part, args.free_from_str()?)),
+                    None => None,
+                };
+
+                AppArguments::Answer {
+                    puzzle,
+                    source,
+                    answer,
+                }
+            }
+            Some("scaffold") => AppArguments::Scaffold {
+                puzzle: parse_puzzle(&mut args)?,
+                download: args.contains("--download"),
+                overwrite: args.contains("--overwrite"),
+            },
+            Some("solve") => {
+                let year = parse_year(&mut args)?;
+                let options = SolveOptions {
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    dhat: args.contains("--dhat"),
+                    alloc_stats: args.contains("--alloc-stats"),
+                    bench: if args.contains("--time") {
+                        Some(BenchConfig::from_args(&mut args)?)
+                    } else {
+                        None
+                    },
+                    input: InputSource::from_args(&mut args)?,
+                };
+                let days: DaySet = args.free_from_str()?;
+
+                if let Some(day) = days.as_single() {
+                    puzzle_of(year, day)?;
+                }
+
+                if options.submit.is_some() && options.input != InputSource::Puzzle {
+                    return Err("only results of the puzzle input can be submitted.".into());
+                }
+
+                AppArguments::Solve {
+                    year,
+                    days,
+                    options,
+                }
+            }
+            #[cfg(feature = "today")]
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
+            Some(x) => {
+                eprintln!("Unknown command: {x}");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("No command specified.");
+                process::exit(1);
+            }
+        };
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        }
+
+        Ok(app_args)
+    }
+}
+
+/// Runs a command. `all` and `time` run the `solutions` in-process if they are compiled in,
+/// otherwise they hand over to the runner binary that compiles them in.
+pub fn run(solutions: Option<&[Solution]>) {
+    match parse() {
+        Err(err) => {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+        Ok(args) => match args {
+            AppArguments::All {
+                year,
+                days,
+                release,
+                timeouts,
+                alloc_stats,
+                jobs,
+            } => all::handle(
+                year,
+                &days,
+                release,
+                &timeouts,
+                alloc_stats,
+                jobs.get(),
+                solutions,
+            ),
+            AppArguments::Time {
+                year,
+                days,
+                all,
+                store,
+                bench,
+                timeouts,
+                alloc_stats,
+            } => time::handle(
+                year,
+                days,
+                all,
+                store,
+                &bench,
+                &timeouts,
+                alloc_stats,
+                solutions,
+            ),
+            AppArguments::Download { puzzle, force } => download::handle(puzzle, force),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Answer {
+                puzzle,
+                source,
+                answer,
+            } => answer::handle(puzzle, &source, answer),
+            AppArguments::Verify { year, days } => verify::handle(year, &days),
+            AppArguments::Checksums { year, days } => checksums::handle(year, &days),
+            AppArguments::Status { year, days, run } => status::handle(year, &days, run),
+            AppArguments::Leaderboard { source, day } => leaderboard::handle(&source, day),
+            AppArguments::Scaffold {
+                puzzle,
+                download,
+                overwrite,
+            } => {
+                // NOTE: downloading first allows to scaffold tests for the examples.
+                if download {
+                    download::handle(puzzle, false);
+                }
+                scaffold::handle(puzzle, overwrite);
+            }
+            AppArguments::Solve {
+                year,
+                days,
+                options,
+            } => solve::handle(year, &days, &options),
+            #[cfg(feature = "today")]
+            AppArguments::Today { wait } => today::handle(wait),
+        },
+    };
+}
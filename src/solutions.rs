@@ -0,0 +1,14 @@
+//! The runner binary of `cargo all` and `cargo time`, built with the `solutions` feature. It
+//! compiles in every solution, so that their parts can be called in-process.
+mod cli;
+
+advent_of_code::global_allocator!();
+
+mod registry {
+    //! Generated by `build.rs` from the solutions in `src/bin`.
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+fn main() {
+    cli::run(Some(registry::SOLUTIONS));
+}
@@ -0,0 +1,243 @@
+/// Heap statistics of solution parts, collected by a counting global allocator.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use tinyjson::JsonValue;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// A global allocator that wraps the system allocator and counts heap usage.
+/// Installed when the `alloc-stats` feature is enabled.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Installs the allocator of the `dhat-heap` or `alloc-stats` feature in a solution binary.
+/// Expanded by [`solution!`](crate::solution) and by the runner binary, which compiles in many
+/// solutions with the `solutions` feature, so that other binaries keep the system allocator.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! global_allocator {
+    () => {
+        #[cfg(feature = "dhat-heap")]
+        #[global_allocator]
+        static ALLOC: dhat::Alloc = dhat::Alloc;
+
+        #[cfg(all(feature = "alloc-stats", not(feature = "dhat-heap")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::CountingAlloc = $crate::template::CountingAlloc;
+    };
+}
+
+fn record_allocation(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(allocated, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Whether the counting allocator is installed. The DHAT profiler takes precedence.
+pub fn is_counting_allocations() -> bool {
+    cfg!(all(feature = "alloc-stats", not(feature = "dhat-heap")))
+}
+
+/// Calls `func` and returns the heap statistics of the call, if the counting allocator is installed.
+pub fn measure_allocations<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_counting_allocations() {
+        return (func(), None);
+    }
+
+    let baseline = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(baseline) as u64,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+    };
+
+    (result, Some(stats))
+}
+
+/// Heap usage of a single execution of a solution step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Maximum number of bytes allocated at once, not counting memory allocated before the step.
+    pub peak_bytes: u64,
+    /// Number of allocations and reallocations.
+    pub allocations: u64,
+}
+
+impl AllocStats {
+    /// Combines the stats of consecutive steps, e.g. to summarize a day.
+    pub fn combine(&self, other: &Self) -> Self {
+        Self {
+            peak_bytes: self.peak_bytes.max(other.peak_bytes),
+            allocations: self.allocations + other.allocations,
+        }
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `4.1 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak, {} allocations",
+            format_bytes(self.peak_bytes),
+            self.allocations
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&AllocStats> for JsonValue {
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AllocStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected allocation stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected allocation stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(AllocStats {
+            peak_bytes: number("peak_bytes")? as u64,
+            allocations: number("allocations")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{AllocStats, format_bytes};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.0 KiB");
+        assert_eq!(format_bytes(4200), "4.1 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 / 2), "1.5 MiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GiB");
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = AllocStats {
+            peak_bytes: 4200,
+            allocations: 12,
+        };
+        assert_eq!(stats.to_string(), "4.1 KiB peak, 12 allocations");
+    }
+
+    #[test]
+    fn combines_stats() {
+        let a = AllocStats {
+            peak_bytes: 100,
+            allocations: 2,
+        };
+        let b = AllocStats {
+            peak_bytes: 50,
+            allocations: 3,
+        };
+        assert_eq!(
+            a.combine(&b),
+            AllocStats {
+                peak_bytes: 100,
+                allocations: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = AllocStats {
+            peak_bytes: 123_456,
+            allocations: 789,
+        };
+        assert_eq!(AllocStats::try_from(&JsonValue::from(&stats)), Ok(stats));
+        assert!(AllocStats::try_from(&JsonValue::Null).is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,222 @@
+use std::{collections::HashMap, fs, io::Error, path::Path, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Part, PuzzleId};
+
+/// Marks the answers of solved parts in puzzle descriptions, e.g. ``Your puzzle answer was `42`.``
+const DESCRIPTION_ANSWER_PREFIX: &str = "Your puzzle answer was";
+
+/// The correct answers of a puzzle, stored in `data/<year>/answers/DD.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// The outcome of comparing a result with the stored answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// There is no stored answer for the part.
+    Unknown,
+}
+
+fn get_answers_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("answers", "json")
+}
+
+impl Answers {
+    /// Rehydrate the answers of a puzzle from its JSON file. If not present, returns empty answers.
+    pub fn read(puzzle: PuzzleId) -> Self {
+        Self::read_file(&get_answers_path(puzzle))
+    }
+
+    /// Dehydrate the answers of a puzzle to a JSON file.
+    pub fn store(&self, puzzle: PuzzleId) -> Result<(), Error> {
+        self.store_file(&get_answers_path(puzzle))
+    }
+
+    /// Rehydrate answers from a JSON file at `path`. If not present, returns empty answers.
+    pub fn read_file(path: &str) -> Self {
+        fs::read_to_string(path)
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Dehydrate answers to a JSON file at `path`, creating its directory if needed.
+    pub fn store_file(&self, path: &str) -> Result<(), Error> {
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Stores `answer` as the correct answer of a part, keeping the answer of the other part.
+    pub fn record(puzzle: PuzzleId, part: Part, answer: &str) -> Result<(), Error> {
+        let mut answers = Self::read(puzzle);
+        answers.set(part, answer);
+        answers.store(puzzle)
+    }
+
+    /// Extracts the answers listed in a puzzle description once a part is solved.
+    pub fn from_description(description: &str) -> Self {
+        let mut answers = description
+            .split(DESCRIPTION_ANSWER_PREFIX)
+            .skip(1)
+            .filter_map(|s| s.split('`').nth(1))
+            .map(ToString::to_string);
+
+        Self {
+            part_1: answers.next(),
+            part_2: answers.next(),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: &str) {
+        let answer = Some(answer.trim().to_string());
+        match part {
+            Part::One => self.part_1 = answer,
+            Part::Two => self.part_2 = answer,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part_1.is_none() && self.part_2.is_none()
+    }
+
+    /// Compares the result of a part with its stored answer.
+    pub fn verify(&self, part: Part, result: &str) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == result.trim() => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for part in Part::ALL {
+            map.insert(
+                format!("part_{part}"),
+                value
+                    .get(part)
+                    .map_or(JsonValue::Null, |answer| JsonValue::String(answer.into())),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let answer = |key: &str| {
+            json.get(key)
+                .map_or(Some(None), |v| {
+                    if v.is_null() {
+                        Some(None)
+                    } else {
+                        v.get::<String>().map(|s| Some(s.clone()))
+                    }
+                })
+                .ok_or(format!("expected `json.{key}` to be null or string."))
+        };
+
+        Ok(Answers {
+            part_1: answer("part_1")?,
+            part_2: answer("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Answers, Verdict};
+    use crate::template::Part;
+
+    #[test]
+    fn extracts_answers_from_descriptions() {
+        let description = "## --- Day 1 ---\n\nYour puzzle answer was `1234`.\n\n## --- Part Two ---\n\nYour puzzle answer was `abc`.\n";
+        let answers = Answers::from_description(description);
+        assert_eq!(answers.part_1.as_deref(), Some("1234"));
+        assert_eq!(answers.part_2.as_deref(), Some("abc"));
+
+        let answers =
+            Answers::from_description("Your puzzle answer was `1234`.\n\n## --- Part Two ---");
+        assert_eq!(answers.part_1.as_deref(), Some("1234"));
+        assert_eq!(answers.part_2, None);
+
+        assert!(Answers::from_description("## --- Day 1 ---").is_empty());
+    }
+
+    #[test]
+    fn verifies_results() {
+        let mut answers = Answers::default();
+        answers.set(Part::One, "42\n");
+
+        assert_eq!(answers.verify(Part::One, "42"), Verdict::Correct);
+        assert_eq!(
+            answers.verify(Part::One, "43"),
+            Verdict::Incorrect {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(answers.verify(Part::Two, "42"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let answers = Answers {
+            part_1: Some("42".into()),
+            part_2: None,
+        };
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn handles_malformed_json() {
+        assert!(Answers::try_from("[]".to_string()).is_err());
+        assert!(Answers::try_from(r#"{ "part_1": 42 }"#.to_string()).is_err());
+        assert_eq!(
+            Answers::try_from("{}".to_string()).unwrap(),
+            Answers::default()
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -4,7 +4,7 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{Part, PuzzleId};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -33,8 +33,8 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let puzzle_path = get_puzzle_path(puzzle);
 
     let args = build_args(
         "read",
@@ -43,67 +43,67 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
-        day,
+        puzzle,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
-
-    let args = build_args(
-        "download",
-        &[
-            "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
-        ],
-        day,
-    );
+/// Downloads the puzzle description, and its input if `include_input` is set.
+pub fn download(puzzle: PuzzleId, include_input: bool) -> Result<Output, AocCommandError> {
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    // NOTE: existing inputs are only overwritten if the caller asks for it, see `include_input`.
+    let mut args = vec![
+        "--overwrite".into(),
+        "--input-file".into(),
+        input_path.to_string(),
+        "--puzzle-file".into(),
+        puzzle_path.to_string(),
+    ];
+
+    if !include_input {
+        args.push("--puzzle-only".into());
+    }
 
-    let output = call_aoc_cli(&args)?;
+    let output = call_aoc_cli(&build_args("download", &args, puzzle))?;
     println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    if include_input {
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(puzzle: PuzzleId, part: Part, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], puzzle);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    // NOTE: the output is captured to log the response, see [`crate::template::Submissions`].
+    call_aoc_cli_captured(&args)
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+fn get_input_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("inputs", "txt")
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("puzzles", "md")
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], puzzle: PuzzleId) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year().to_string(),
+        "--day".into(),
+        puzzle.day().to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
@@ -123,3 +123,20 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/// Calls aoc-cli like [`call_aoc_cli`], but captures its output and prints it once the call finished.
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCommandError> {
+    let output = Command::new("aoc")
+        .args(args)
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
@@ -0,0 +1,401 @@
+/// Built-in Advent of Code client, with aoc-cli as an optional backend.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{Part, PuzzleId, Year, aoc_cli, html};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the client to the Advent of Code servers if `AOC_USER_AGENT` is not set.
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+const SESSION_FILE: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum AocError {
+    /// Neither `AOC_SESSION` nor the session file are set.
+    MissingSession,
+    /// The server responded with an error status, e.g. 404 for inputs that are not unlocked yet.
+    Status(u16, String),
+    /// The server could not be reached.
+    Transport(String),
+    Io(io::Error),
+    Cli(aoc_cli::AocCommandError),
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::MissingSession => write!(
+                f,
+                "no session cookie found. Set the `AOC_SESSION` environment variable or create the file `~/{SESSION_FILE}`."
+            ),
+            AocError::Status(status, url) => {
+                write!(f, "request to {url} failed with status {status}.")
+            }
+            AocError::Transport(e) => write!(f, "request failed: {e}"),
+            AocError::Io(e) => write!(f, "{e}"),
+            AocError::Cli(e) => write!(f, "failed to call aoc-cli: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(value: io::Error) -> Self {
+        AocError::Io(value)
+    }
+}
+
+impl From<aoc_cli::AocCommandError> for AocError {
+    fn from(value: aoc_cli::AocCommandError) -> Self {
+        AocError::Cli(value)
+    }
+}
+
+impl From<ureq::Error> for AocError {
+    fn from(value: ureq::Error) -> Self {
+        match value {
+            ureq::Error::Status(status, response) => {
+                AocError::Status(status, response.get_url().to_string())
+            }
+            ureq::Error::Transport(e) => AocError::Transport(e.to_string()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// HTTP client for the Advent of Code website, authenticated with a session cookie.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    /// Creates a client for a base URL. Requests identify with the user agent in `AOC_USER_AGENT`,
+    /// which the maintainer of Advent of Code asks to contain a way to contact you.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let user_agent = env::var("AOC_USER_AGENT")
+            .ok()
+            .filter(|x| !x.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_USER_AGENT.into());
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(&user_agent)
+                .build(),
+        }
+    }
+
+    /// Creates a client for the base URL in `AOC_BASE_URL`, by default `https://adventofcode.com`.
+    /// The session cookie is read from `AOC_SESSION`, falling back to `~/.adventofcode.session`.
+    pub fn from_env() -> Result<Self, AocError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(home_dir()?.join(SESSION_FILE)).ok())
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(AocError::MissingSession)?;
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Downloads the personal input of a puzzle.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocError> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Downloads the HTML page of a puzzle, which includes part two once part one is solved.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocError> {
+        self.get(&self.day_url(puzzle))
+    }
+
+    /// Submits an answer, returning the HTML page of the response.
+    pub fn submit(&self, puzzle: PuzzleId, part: Part, answer: &str) -> Result<String, AocError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(puzzle)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer.trim())])?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Downloads the JSON export of a private leaderboard, identified by the id of its owner.
+    pub fn leaderboard(&self, year: Year, id: u64) -> Result<String, AocError> {
+        self.get(&format!(
+            "{}/{year}/leaderboard/private/view/{id}.json",
+            self.base_url
+        ))
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocError> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()).call()?;
+        Ok(response.into_string()?)
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year(),
+            puzzle.day().into_inner()
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// How the template talks to Advent of Code, selected with the `AOC_BACKEND` environment variable.
+pub enum Backend {
+    /// The built-in [`AocClient`], used by default.
+    Native(AocClient),
+    /// The external `aoc` binary, used for `AOC_BACKEND=aoc-cli`.
+    AocCli,
+}
+
+impl Backend {
+    /// Selects the backend, failing early if it is not configured.
+    pub fn from_env() -> Result<Self, AocError> {
+        match env::var("AOC_BACKEND").as_deref() {
+            Ok("aoc-cli") => {
+                aoc_cli::check()?;
+                Ok(Backend::AocCli)
+            }
+            _ => AocClient::from_env().map(Backend::Native),
+        }
+    }
+
+    /// Selects the backend like [`Backend::from_env`], exiting with an error if it is not configured.
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env().unwrap_or_else(|e| {
+            match e {
+                AocError::Cli(_) => eprintln!(
+                    "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+                ),
+                e => eprintln!("{e}"),
+            }
+            std::process::exit(1);
+        })
+    }
+
+    /// Downloads the description of a puzzle to the data directory, and its input if
+    /// `include_input` is set.
+    pub fn download(&self, puzzle: PuzzleId, include_input: bool) -> Result<(), AocError> {
+        let client = match self {
+            Backend::Native(client) => client,
+            Backend::AocCli => {
+                return aoc_cli::download(puzzle, include_input)
+                    .map(|_| ())
+                    .map_err(Into::into);
+            }
+        };
+
+        let input_path = puzzle.data_path("inputs", "txt");
+        let puzzle_path = puzzle.data_path("puzzles", "md");
+
+        if include_input {
+            write_file(&input_path, &client.input(puzzle)?)?;
+            println!("🎄 Successfully wrote input to \"{input_path}\".");
+        }
+
+        self.refresh_description(puzzle)?;
+        println!("🎄 Successfully wrote puzzle to \"{puzzle_path}\".");
+        Ok(())
+    }
+
+    /// Downloads the description of a puzzle to the data directory and prints it.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<(), AocError> {
+        match self {
+            Backend::Native(_) => println!("{}", self.refresh_description(puzzle)?),
+            Backend::AocCli => {
+                aoc_cli::read(puzzle)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Downloads the description of a puzzle to the data directory, returning it.
+    pub fn refresh_description(&self, puzzle: PuzzleId) -> Result<String, AocError> {
+        let puzzle_path = puzzle.data_path("puzzles", "md");
+
+        match self {
+            Backend::Native(client) => {
+                let description = html::description_to_markdown(&client.puzzle(puzzle)?);
+                write_file(&puzzle_path, &description)?;
+                Ok(description)
+            }
+            Backend::AocCli => {
+                aoc_cli::download(puzzle, false)?;
+                Ok(fs::read_to_string(puzzle_path)?)
+            }
+        }
+    }
+
+    /// Submits an answer and prints the response, returning its text.
+    pub fn submit(&self, puzzle: PuzzleId, part: Part, answer: &str) -> Result<String, AocError> {
+        match self {
+            Backend::Native(client) => {
+                let response = html::response_to_markdown(&client.submit(puzzle, part, answer)?);
+                println!("{response}");
+                Ok(response)
+            }
+            Backend::AocCli => {
+                let output = aoc_cli::submit(puzzle, part, answer)?;
+                Ok([&output.stdout, &output.stderr]
+                    .map(|x| String::from_utf8_lossy(x).into_owned())
+                    .join("\n"))
+            }
+        }
+    }
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), io::Error> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::{AocClient, AocError};
+    use crate::{
+        day,
+        template::{Part, PuzzleId},
+        year,
+    };
+
+    /// A request as received by the mock server: the request line, the lowercased headers and the body.
+    struct Request {
+        line: String,
+        headers: Vec<String>,
+        body: String,
+    }
+
+    /// Serves a single request with the status and body, returning a client for the server.
+    fn serve(status: &str, body: &str) -> (AocClient, JoinHandle<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+
+            let mut headers = vec![];
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                headers.push(header.trim().to_lowercase());
+            }
+
+            let length = headers
+                .iter()
+                .find_map(|x| x.strip_prefix("content-length: "))
+                .map_or(0, |x| x.parse().unwrap());
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            stream.write_all(response.as_bytes()).unwrap();
+
+            Request {
+                line: line.trim().to_string(),
+                headers,
+                body: String::from_utf8(body).unwrap(),
+            }
+        });
+
+        (AocClient::new(&format!("http://{addr}"), "abc"), server)
+    }
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(year!(2025), day!(3)).unwrap()
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (client, server) = serve("200 OK", "1\n2\n");
+        assert_eq!(client.input(puzzle()).unwrap(), "1\n2\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request.line, "GET /2025/day/3/input HTTP/1.1");
+        assert!(request.headers.contains(&"cookie: session=abc".into()));
+        assert!(
+            request
+                .headers
+                .iter()
+                .any(|x| x.starts_with("user-agent: "))
+        );
+    }
+
+    #[test]
+    fn downloads_puzzle() {
+        let (client, server) = serve("200 OK", "<main></main>");
+        assert_eq!(client.puzzle(puzzle()).unwrap(), "<main></main>");
+
+        let request = server.join().unwrap();
+        assert_eq!(request.line, "GET /2025/day/3 HTTP/1.1");
+        assert!(request.headers.contains(&"cookie: session=abc".into()));
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (client, server) = serve("200 OK", "<main>That's the right answer!</main>");
+        let response = client.submit(puzzle(), Part::Two, " 42\n").unwrap();
+        assert_eq!(response, "<main>That's the right answer!</main>");
+
+        let request = server.join().unwrap();
+        assert_eq!(request.line, "POST /2025/day/3/answer HTTP/1.1");
+        assert!(request.headers.contains(&"cookie: session=abc".into()));
+        assert_eq!(request.body, "level=2&answer=42");
+    }
+
+    #[test]
+    fn maps_error_status() {
+        let (client, server) = serve("404 Not Found", "");
+        let result = client.input(puzzle());
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(AocError::Status(404, _))));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,235 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::time::Duration;
+
+/// Controls how long and how often a solution part is executed when benching.
+///
+/// Can be parsed from and forwarded as command-line flags, so that the budget passed to
+/// `cargo time` or `cargo solve --time` reaches the solution binaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Targeted total execution time of the samples, used to derive the number of samples.
+    pub bench_time: Duration,
+    /// Number of executions before sampling. Warmup stops early once it exceeds a tenth of `bench_time`.
+    pub warmup: u32,
+    pub min_samples: u128,
+    pub max_samples: u128,
+    /// Hard limit on the wall-clock time spent benching, which takes precedence over `min_samples`.
+    pub max_bench_time: Option<Duration>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            bench_time: Duration::from_secs(1),
+            warmup: 3,
+            min_samples: 10,
+            max_samples: 10000,
+            max_bench_time: None,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Parses the bench flags, falling back to the defaults for missing flags.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        let default = Self::default();
+
+        let config = Self {
+            bench_time: args
+                .opt_value_from_fn("--bench-time", parse_duration)?
+                .unwrap_or(default.bench_time),
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(default.warmup),
+            min_samples: args
+                .opt_value_from_str("--min-samples")?
+                .unwrap_or(default.min_samples),
+            max_samples: args
+                .opt_value_from_str("--max-samples")?
+                .unwrap_or(default.max_samples),
+            max_bench_time: args.opt_value_from_fn("--max-bench-time", parse_duration)?,
+        };
+
+        if config.min_samples == 0 {
+            return Err(BenchConfigError("`--min-samples` needs to be at least 1".into()).into());
+        }
+
+        if config.min_samples > config.max_samples {
+            return Err(BenchConfigError(
+                "`--min-samples` cannot be larger than `--max-samples`".into(),
+            )
+            .into());
+        }
+
+        Ok(config)
+    }
+
+    /// Converts the config back into flags that [`BenchConfig::from_args`] understands.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--bench-time".to_string(),
+            format_duration(self.bench_time),
+            "--warmup".to_string(),
+            self.warmup.to_string(),
+            "--min-samples".to_string(),
+            self.min_samples.to_string(),
+            "--max-samples".to_string(),
+            self.max_samples.to_string(),
+        ];
+
+        if let Some(max_bench_time) = self.max_bench_time {
+            args.push("--max-bench-time".to_string());
+            args.push(format_duration(max_bench_time));
+        }
+
+        args
+    }
+
+    /// Number of samples to take for a solution that took `base_time` on its first execution.
+    pub fn sample_count(&self, base_time: Duration) -> u128 {
+        (self.bench_time.as_nanos() / base_time.as_nanos().max(10))
+            .clamp(self.min_samples, self.max_samples)
+    }
+}
+
+/// Parses a duration such as `500ms`, `1.5s` or `2m`. Values without a unit are seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, BenchConfigError> {
+    let err = || {
+        BenchConfigError(format!(
+            "invalid duration `{s}`, expecting e.g. `500ms` or `2s`"
+        ))
+    };
+
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value.parse().map_err(|_| err())?;
+
+    let nanos_per_unit = match unit {
+        "ns" => 1.0,
+        "µs" | "us" => 1e3,
+        "ms" => 1e6,
+        "" | "s" => 1e9,
+        "m" => 60e9,
+        _ => return Err(err()),
+    };
+
+    let nanos = (value * nanos_per_unit).round();
+    if !nanos.is_finite() || nanos > u64::MAX as f64 {
+        return Err(err());
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Ok(Duration::from_nanos(nanos as u64))
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{}ns", duration.as_nanos())
+}
+
+/// An error which can be returned when parsing a [`BenchConfig`].
+#[derive(Debug)]
+pub struct BenchConfigError(String);
+
+impl Error for BenchConfigError {}
+
+impl Display for BenchConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{ffi::OsString, time::Duration};
+
+    use super::{BenchConfig, parse_duration};
+
+    fn parse(args: &[&str]) -> Result<BenchConfig, Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect());
+        BenchConfig::from_args(&mut args)
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_duration("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_duration("2").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_duration("2m").unwrap(), Duration::from_secs(120));
+        assert_eq!(parse_duration("20us").unwrap(), Duration::from_micros(20));
+        assert_eq!(parse_duration("42ns").unwrap(), Duration::from_nanos(42));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("ms").is_err());
+        assert!(parse_duration("2h").is_err());
+        assert!(parse_duration("-1s").is_err());
+    }
+
+    #[test]
+    fn parses_flags() {
+        assert_eq!(parse(&[]).unwrap(), BenchConfig::default());
+
+        let config = parse(&[
+            "--bench-time",
+            "5s",
+            "--warmup",
+            "0",
+            "--min-samples",
+            "3",
+            "--max-samples",
+            "100",
+            "--max-bench-time",
+            "30s",
+        ])
+        .unwrap();
+
+        assert_eq!(config.bench_time, Duration::from_secs(5));
+        assert_eq!(config.warmup, 0);
+        assert_eq!(config.min_samples, 3);
+        assert_eq!(config.max_samples, 100);
+        assert_eq!(config.max_bench_time, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn rejects_invalid_sample_limits() {
+        assert!(parse(&["--min-samples", "0"]).is_err());
+        assert!(parse(&["--min-samples", "20", "--max-samples", "10"]).is_err());
+    }
+
+    #[test]
+    fn roundtrips_flags() {
+        let config = BenchConfig {
+            bench_time: Duration::from_nanos(123_456_789),
+            warmup: 1,
+            min_samples: 5,
+            max_samples: 50,
+            max_bench_time: Some(Duration::from_secs(10)),
+        };
+
+        let args = config.to_args();
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        assert_eq!(parse(&args).unwrap(), config);
+    }
+
+    #[test]
+    fn derives_sample_count() {
+        let config = BenchConfig::default();
+        assert_eq!(config.sample_count(Duration::from_millis(10)), 100);
+        assert_eq!(config.sample_count(Duration::from_secs(10)), 10);
+        assert_eq!(config.sample_count(Duration::from_nanos(20)), 10000);
+
+        let config = BenchConfig {
+            bench_time: Duration::from_secs(10),
+            min_samples: 1,
+            max_samples: 1_000_000,
+            ..BenchConfig::default()
+        };
+        assert_eq!(config.sample_count(Duration::from_secs(10)), 1);
+        assert_eq!(config.sample_count(Duration::from_nanos(20)), 1_000_000);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,164 @@
+use crate::template::{AllDays, Day, Year};
+
+/// The month in which advent of code takes place.
+pub const EVENT_MONTH: u32 = 12;
+
+/// Puzzles unlock at midnight EST (UTC-5).
+pub const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Describes the puzzle calendar of a single advent of code event.
+///
+/// ```
+/// # use advent_of_code::template::{Calendar, Day, Year};
+/// let calendar = Calendar::of(Year::new(2025).unwrap());
+/// assert_eq!(calendar.len(), 12);
+/// assert!(!calendar.contains(Day::new(13).unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calendar {
+    year: Year,
+    days: u8,
+    start: u8,
+}
+
+impl Calendar {
+    /// Returns the calendar of the event that takes place in `year`.
+    pub const fn of(year: Year) -> Self {
+        // NOTE: starting with 2025, the event was shortened to 12 days.
+        let days = if year.into_inner() >= 2025 { 12 } else { 25 };
+        Self {
+            year,
+            days,
+            start: 1,
+        }
+    }
+
+    pub fn year(self) -> Year {
+        self.year
+    }
+
+    /// The number of puzzles of the event.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(self) -> u8 {
+        self.days
+    }
+
+    /// The day of december on which the first puzzle unlocks.
+    pub const fn start(self) -> u8 {
+        self.start
+    }
+
+    /// The last day of the event.
+    pub fn last_day(self) -> Day {
+        // NOTE: every event has between 1 and 25 days.
+        Day::new(self.days).unwrap()
+    }
+
+    /// Returns `true` if `day` is part of the event.
+    pub const fn contains(self, day: Day) -> bool {
+        day.into_inner() <= self.days
+    }
+
+    /// Returns the puzzle day that unlocks on the given day of december, if any.
+    pub fn day_of_december(self, date: u8) -> Option<Day> {
+        let day = Day::new(date.checked_sub(self.start)? + 1)?;
+        self.contains(day).then_some(day)
+    }
+
+    /// An iterator that yields every day of the event.
+    pub fn days(self) -> AllDays {
+        AllDays::new(self.last_day())
+    }
+
+    /// Unix timestamp at which the puzzle of a day unlocks.
+    ///
+    /// ```
+    /// # use advent_of_code::template::{Calendar, Day, Year};
+    /// let calendar = Calendar::of(Year::new(2025).unwrap());
+    /// assert_eq!(calendar.unlock_timestamp(Day::new(1).unwrap()), 1_764_565_200);
+    /// ```
+    pub fn unlock_timestamp(self, day: Day) -> u64 {
+        let date = self.start + day.into_inner() - 1;
+        let days = days_since_epoch(self.year.into_inner(), EVENT_MONTH, u32::from(date));
+
+        // NOTE: midnight in UTC-5 is 05:00 in UTC.
+        let offset = u64::from(SERVER_UTC_OFFSET.unsigned_abs()) * 3600;
+        days * 86400 + offset
+    }
+}
+
+/// Days between the unix epoch and a date, using the `days_from_civil` algorithm of
+/// <https://howardhinnant.github.io/date_algorithms.html>.
+fn days_since_epoch(year: u16, month: u32, day: u32) -> u64 {
+    // NOTE: years start in march, so that leap days are at the end of a year.
+    let year = u64::from(year) - u64::from(month <= 2);
+    let month = u64::from(month);
+
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + u64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Calendar;
+    use crate::{day, year};
+
+    #[test]
+    fn knows_event_lengths() {
+        assert_eq!(Calendar::of(year!(2015)).len(), 25);
+        assert_eq!(Calendar::of(year!(2024)).len(), 25);
+        assert_eq!(Calendar::of(year!(2025)).len(), 12);
+    }
+
+    #[test]
+    fn contains_event_days() {
+        let calendar = Calendar::of(year!(2025));
+        assert!(calendar.contains(day!(1)));
+        assert!(calendar.contains(day!(12)));
+        assert!(!calendar.contains(day!(13)));
+        assert!(Calendar::of(year!(2024)).contains(day!(25)));
+    }
+
+    #[test]
+    fn maps_dates_to_days() {
+        let calendar = Calendar::of(year!(2025));
+        assert_eq!(calendar.day_of_december(0), None);
+        assert_eq!(calendar.day_of_december(1), Some(day!(1)));
+        assert_eq!(calendar.day_of_december(12), Some(day!(12)));
+        assert_eq!(calendar.day_of_december(13), None);
+    }
+
+    #[test]
+    fn iterates_event_days() {
+        assert_eq!(Calendar::of(year!(2025)).days().count(), 12);
+        assert_eq!(Calendar::of(year!(2023)).days().last(), Some(day!(25)));
+    }
+
+    #[test]
+    fn computes_unlock_timestamps() {
+        assert_eq!(
+            Calendar::of(year!(2025)).unlock_timestamp(day!(1)),
+            1_764_565_200
+        );
+        assert_eq!(
+            Calendar::of(year!(2025)).unlock_timestamp(day!(12)),
+            1_765_515_600
+        );
+        assert_eq!(
+            Calendar::of(year!(2016)).unlock_timestamp(day!(25)),
+            1_482_642_000
+        );
+        assert_eq!(
+            Calendar::of(year!(2020)).unlock_timestamp(day!(1)),
+            1_606_798_800
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
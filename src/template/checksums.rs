@@ -0,0 +1,255 @@
+use std::{collections::BTreeMap, collections::HashMap, fs, io::Error, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, Year};
+
+/// Checksum of an input, the hex-encoded 64-bit FNV-1a hash of its bytes.
+///
+/// ```
+/// # use advent_of_code::template::checksum;
+/// assert_eq!(checksum(b""), "cbf29ce484222325");
+/// ```
+pub fn checksum(input: &[u8]) -> String {
+    let hash = input.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Checksums of the input of a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputChecksum {
+    /// Checksum of the input when it was downloaded.
+    pub downloaded: Option<String>,
+    /// Checksum of the input when its answers were recorded.
+    pub answered: Option<String>,
+}
+
+/// How the current input of a day compares to its recorded checksums.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputStatus {
+    Missing,
+    /// No checksum was recorded for the input.
+    Untracked,
+    Unchanged,
+    /// The input changed since it was downloaded, but no answers were recorded for it.
+    EditedSinceDownload,
+    /// The input changed since its answers were recorded, so they may not match anymore.
+    ChangedSinceAnswers,
+}
+
+impl InputStatus {
+    pub fn of(input: Option<&str>, recorded: Option<&InputChecksum>) -> Self {
+        let Some(input) = input.filter(|x| !x.is_empty()) else {
+            return InputStatus::Missing;
+        };
+
+        let current = checksum(input.as_bytes());
+        let recorded = recorded.cloned().unwrap_or_default();
+
+        match (recorded.answered, recorded.downloaded) {
+            (Some(answered), _) if answered != current => InputStatus::ChangedSinceAnswers,
+            (Some(_), _) => InputStatus::Unchanged,
+            (None, Some(downloaded)) if downloaded != current => InputStatus::EditedSinceDownload,
+            (None, Some(_)) => InputStatus::Unchanged,
+            (None, None) => InputStatus::Untracked,
+        }
+    }
+}
+
+fn get_checksums_path(year: Year) -> String {
+    format!("data/{year}/checksums.json")
+}
+
+/// Checksums of the inputs of a year, stored in `data/<year>/checksums.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Checksums {
+    pub data: BTreeMap<Day, InputChecksum>,
+}
+
+impl Checksums {
+    /// Rehydrate the checksums of a year from its JSON file. If not present, returns empty checksums.
+    pub fn read(year: Year) -> Self {
+        fs::read_to_string(get_checksums_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Checksums::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Dehydrate the checksums of a year to its JSON file.
+    pub fn store(&self, year: Year) -> Result<(), Error> {
+        fs::create_dir_all(format!("data/{year}"))?;
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(get_checksums_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Records the checksum of the input of a puzzle after it was downloaded.
+    pub fn record_download(puzzle: PuzzleId) -> Result<(), Error> {
+        Self::update(puzzle, |checksum, input| checksum.downloaded = Some(input))
+    }
+
+    /// Records the checksum of the input of a puzzle after its answers were recorded.
+    pub fn record_answers(puzzle: PuzzleId) -> Result<(), Error> {
+        Self::update(puzzle, |checksum, input| checksum.answered = Some(input))
+    }
+
+    /// The status of the input of a puzzle, read from its input file.
+    pub fn status(&self, puzzle: PuzzleId) -> InputStatus {
+        let input = fs::read_to_string(puzzle.data_path("inputs", "txt")).ok();
+        InputStatus::of(input.as_deref(), self.data.get(&puzzle.day()))
+    }
+
+    fn update(
+        puzzle: PuzzleId,
+        func: impl FnOnce(&mut InputChecksum, String),
+    ) -> Result<(), Error> {
+        // NOTE: there is nothing to record for puzzles without an input.
+        let Ok(input) = fs::read(puzzle.data_path("inputs", "txt")) else {
+            return Ok(());
+        };
+
+        let mut checksums = Self::read(puzzle.year());
+        func(
+            checksums.data.entry(puzzle.day()).or_default(),
+            checksum(&input),
+        );
+        checksums.store(puzzle.year())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Checksums> for JsonValue {
+    fn from(value: &Checksums) -> Self {
+        let map = value
+            .data
+            .iter()
+            .map(|(day, checksum)| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                for (key, value) in [
+                    ("downloaded", &checksum.downloaded),
+                    ("answered", &checksum.answered),
+                ] {
+                    if let Some(value) = value {
+                        map.insert(key.into(), JsonValue::String(value.clone()));
+                    }
+                }
+                (day.to_string(), JsonValue::Object(map))
+            })
+            .collect();
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Checksums {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let data = json
+            .iter()
+            .map(|(day, value)| {
+                let day: Day = day
+                    .parse()
+                    .map_err(|_| format!("expected `{day}` to be a day."))?;
+
+                let map = value
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or(format!("expected `json.{day}` to be an object."))?;
+
+                let checksum = |key: &str| match map.get(key) {
+                    None => Ok(None),
+                    Some(v) => v
+                        .get::<String>()
+                        .map(|s| Some(s.clone()))
+                        .ok_or(format!("expected `json.{day}.{key}` to be a string.")),
+                };
+
+                Ok((
+                    day,
+                    InputChecksum {
+                        downloaded: checksum("downloaded")?,
+                        answered: checksum("answered")?,
+                    },
+                ))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Checksums { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Checksums, InputChecksum, InputStatus, checksum};
+    use crate::day;
+
+    fn recorded(downloaded: Option<&str>, answered: Option<&str>) -> InputChecksum {
+        InputChecksum {
+            downloaded: downloaded.map(checksum_of),
+            answered: answered.map(checksum_of),
+        }
+    }
+
+    fn checksum_of(input: &str) -> String {
+        checksum(input.as_bytes())
+    }
+
+    #[test]
+    fn hashes_inputs() {
+        assert_eq!(checksum(b"a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum(b"1\n2\n"), checksum(b"1\n2"));
+    }
+
+    #[test]
+    fn compares_inputs() {
+        let status = |input, recorded| InputStatus::of(input, Some(&recorded));
+
+        assert_eq!(InputStatus::of(None, None), InputStatus::Missing);
+        assert_eq!(InputStatus::of(Some(""), None), InputStatus::Missing);
+        assert_eq!(InputStatus::of(Some("1"), None), InputStatus::Untracked);
+        assert_eq!(
+            status(Some("1"), recorded(Some("1"), None)),
+            InputStatus::Unchanged
+        );
+        assert_eq!(
+            status(Some("2"), recorded(Some("1"), None)),
+            InputStatus::EditedSinceDownload
+        );
+        assert_eq!(
+            status(Some("2"), recorded(Some("1"), Some("2"))),
+            InputStatus::Unchanged
+        );
+        assert_eq!(
+            status(Some("3"), recorded(Some("1"), Some("2"))),
+            InputStatus::ChangedSinceAnswers
+        );
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut checksums = Checksums::default();
+        checksums
+            .data
+            .insert(day!(1), recorded(Some("1"), Some("2")));
+        checksums.data.insert(day!(12), recorded(Some("1"), None));
+
+        let json = JsonValue::from(&checksums).stringify().unwrap();
+        assert_eq!(Checksums::try_from(json).unwrap(), checksums);
+        assert!(Checksums::try_from("[]".to_string()).is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
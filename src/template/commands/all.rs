@@ -1,5 +1,36 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{
+    DaySet, StoredDayStatus, Timeouts, Year,
+    run_multi::{run_in_runner, run_multi, run_parallel},
+    runner::Solution,
+};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(
+    year: Year,
+    days: &DaySet,
+    is_release: bool,
+    timeouts: &Timeouts,
+    alloc_stats: bool,
+    jobs: usize,
+    solutions: Option<&[Solution]>,
+) {
+    // NOTE: parallel days and days that may time out run in their own binaries anyway.
+    if solutions.is_none() && jobs == 1 && *timeouts == Timeouts::default() {
+        run_in_runner(is_release, alloc_stats);
+    }
+
+    let days_to_run = days.resolve(year, &StoredDayStatus::read(year));
+
+    if jobs > 1 {
+        run_parallel(year, &days_to_run, is_release, timeouts, alloc_stats, jobs);
+    } else {
+        run_multi(
+            year,
+            &days_to_run,
+            is_release,
+            None,
+            timeouts,
+            alloc_stats,
+            solutions.unwrap_or_default(),
+        );
+    }
 }
@@ -0,0 +1,80 @@
+use std::{fs, process};
+
+use crate::template::{Answers, Checksums, InputSource, Part, PuzzleId};
+
+/// Stores the answer to a part, or imports the answers listed in the puzzle description.
+/// For examples, stores the expected answer to a part.
+pub fn handle(puzzle: PuzzleId, source: &InputSource, answer: Option<(Part, String)>) {
+    let Some(path) = source.answers_path(puzzle) else {
+        eprintln!("Answers can only be stored for puzzle inputs and examples.");
+        process::exit(1);
+    };
+
+    let answers = match (answer, source) {
+        (Some((part, answer)), _) => {
+            let mut answers = Answers::read_file(&path);
+            answers.set(part, &answer);
+            answers
+        }
+        (None, InputSource::Puzzle) => import_from_description(puzzle),
+        (None, _) => {
+            eprintln!(
+                "Pass the expected answer of the {source}, e.g. `cargo answer {} --part 1 42 --example`.",
+                puzzle.day()
+            );
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = answers.store_file(&path) {
+        eprintln!("Failed to store answers: {e}");
+        process::exit(1);
+    }
+
+    for part in Part::ALL {
+        if let Some(answer) = answers.get(part) {
+            println!("🎄 Part {part}: {answer}");
+        }
+    }
+
+    if *source == InputSource::Puzzle
+        && let Err(e) = Checksums::record_answers(puzzle)
+    {
+        eprintln!("Failed to record the checksum of the input: {e}");
+    }
+
+    match source {
+        InputSource::Puzzle => println!("Stored answers of day {}.", puzzle.day()),
+        _ => println!("Stored answers of the {source} of day {}.", puzzle.day()),
+    }
+}
+
+fn import_from_description(puzzle: PuzzleId) -> Answers {
+    let path = puzzle.data_path("puzzles", "md");
+
+    let Ok(description) = fs::read_to_string(&path) else {
+        eprintln!(
+            "Could not read puzzle description \"{path}\". Run `cargo read {}` to fetch it.",
+            puzzle.day()
+        );
+        process::exit(1);
+    };
+
+    let found = Answers::from_description(&description);
+
+    if found.is_empty() {
+        eprintln!(
+            "The puzzle description does not list any answers. Run `cargo read {}` to refresh it after solving a part.",
+            puzzle.day()
+        );
+        process::exit(1);
+    }
+
+    let mut answers = Answers::read(puzzle);
+    for part in Part::ALL {
+        if let Some(answer) = found.get(part) {
+            answers.set(part, answer);
+        }
+    }
+    answers
+}
@@ -0,0 +1,44 @@
+use std::process;
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Checksums, DaySet, InputStatus, PuzzleId, StoredDayStatus, Year,
+    all_days,
+};
+
+/// Compares the inputs of the selected days with the checksums recorded when they were downloaded
+/// and when their answers were recorded. Exits with a non-zero status code if any input changed
+/// since its answers were recorded.
+pub fn handle(year: Year, days: &DaySet) {
+    let days_to_check = days.resolve(year, &StoredDayStatus::read(year));
+    let checksums = Checksums::read(year);
+    let mut changed = 0;
+
+    let statuses = all_days(year)
+        .filter(|day| days_to_check.contains(day))
+        .filter_map(|day| PuzzleId::new(year, day))
+        .map(|puzzle| (puzzle, checksums.status(puzzle)))
+        .filter(|(_, status)| *status != InputStatus::Missing);
+
+    for (puzzle, status) in statuses {
+        let status = match status {
+            InputStatus::Missing => continue,
+            InputStatus::Untracked => "no checksum recorded",
+            InputStatus::Unchanged => "✔ unchanged",
+            InputStatus::EditedSinceDownload => "edited since download",
+            InputStatus::ChangedSinceAnswers => {
+                changed += 1;
+                "✖ changed since its answers were recorded"
+            }
+        };
+
+        println!("Day {}: {status}", puzzle.day());
+    }
+
+    if changed > 0 {
+        println!();
+        println!(
+            "{ANSI_BOLD}✘ {changed} input(s) changed since their answers were recorded.{ANSI_RESET} Run `cargo verify` to check the answers, and `cargo answer <day>` to record them again."
+        );
+        process::exit(1);
+    }
+}
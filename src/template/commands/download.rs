@@ -1,16 +1,50 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use crate::template::{
+    Checksums, PuzzleId,
+    aoc_client::{AocError, Backend},
+    store_examples,
+};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+pub fn handle(puzzle: PuzzleId, force: bool) {
+    let backend = Backend::from_env_or_exit();
+
+    for folder in ["inputs", "examples", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(format!("data/{}/{folder}", puzzle.year())) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = download(&backend, puzzle, force) {
+        eprintln!("Failed to download day {}: {e}", puzzle.day());
         process::exit(1);
     };
 }
+
+/// Downloads the description and the input of a puzzle. Existing inputs are kept unless `force`
+/// is set, as they may have been edited. The checksum of a downloaded input is recorded.
+pub fn download(backend: &Backend, puzzle: PuzzleId, force: bool) -> Result<(), AocError> {
+    let input_path = puzzle.data_path("inputs", "txt");
+
+    // NOTE: `scaffold` creates empty inputs, which are safe to overwrite.
+    let has_input = fs::metadata(&input_path).is_ok_and(|x| x.len() > 0);
+    let include_input = force || !has_input;
+
+    if !include_input {
+        println!(
+            "Input \"{input_path}\" exists already, only downloading the puzzle. Pass `--force` to overwrite it."
+        );
+    }
+
+    backend.download(puzzle, include_input)?;
+
+    if include_input && let Err(e) = Checksums::record_download(puzzle) {
+        eprintln!("Failed to record the checksum of \"{input_path}\": {e}");
+    }
+
+    if let Err(e) = store_examples(puzzle) {
+        eprintln!("Failed to store the examples of the puzzle: {e}");
+    }
+
+    Ok(())
+}
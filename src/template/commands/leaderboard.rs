@@ -0,0 +1,62 @@
+use std::{env, fs, process};
+
+use crate::template::{
+    Day, Leaderboard, PuzzleId, Year, aoc_client::AocClient, render_day, render_ranking,
+};
+
+/// Where to read the leaderboard from.
+pub enum LeaderboardSource {
+    /// A JSON export saved to disk.
+    File(String),
+    /// The private leaderboard with the given id, downloaded with the built-in client.
+    Download(Year, Option<u64>),
+}
+
+/// Prints the ranking of a private leaderboard, or the completion times of a single day.
+pub fn handle(source: &LeaderboardSource, day: Option<Day>) {
+    let json = match source {
+        LeaderboardSource::File(path) => fs::read_to_string(path).unwrap_or_else(|e| {
+            eprintln!("failed to read leaderboard \"{path}\": {e}");
+            process::exit(1);
+        }),
+        LeaderboardSource::Download(year, id) => download(*year, *id),
+    };
+
+    let leaderboard = Leaderboard::try_from(json).unwrap_or_else(|e| {
+        eprintln!("failed to parse leaderboard: {e}");
+        process::exit(1);
+    });
+
+    let lines = match day {
+        None => render_ranking(&leaderboard),
+        Some(day) => {
+            let Some(puzzle) = PuzzleId::new(leaderboard.year, day) else {
+                eprintln!("day {day} is not part of the {} event.", leaderboard.year);
+                process::exit(1);
+            };
+            println!("Day {day}");
+            println!();
+            render_day(&leaderboard, puzzle)
+        }
+    };
+
+    for line in lines {
+        println!("{line}");
+    }
+}
+
+fn download(year: Year, id: Option<u64>) -> String {
+    let Some(id) = id.or_else(|| env::var("AOC_LEADERBOARD_ID").ok()?.trim().parse().ok()) else {
+        eprintln!(
+            "no leaderboard specified. Pass a JSON export, `--id <id>`, or set the `AOC_LEADERBOARD_ID` environment variable."
+        );
+        process::exit(1);
+    };
+
+    AocClient::from_env()
+        .and_then(|client| client.leaderboard(year, id))
+        .unwrap_or_else(|e| {
+            eprintln!("failed to download leaderboard: {e}");
+            process::exit(1);
+        })
+}
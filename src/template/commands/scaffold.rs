@@ -1,10 +1,10 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
     process,
 };
 
-use crate::template::Day;
+use crate::template::{Answers, InputSource, Part, PuzzleId, Year};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
@@ -19,18 +19,24 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Renders the expected answer of a part in the scaffolded tests.
+fn expected_answer(answers: &Answers, part: Part) -> String {
+    answers
+        .get(part)
+        .map_or("None".into(), |answer| format!("Some({answer:?})"))
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: PuzzleId, overwrite: bool) {
+    let input_path = puzzle.data_path("inputs", "txt");
+    let example_path = puzzle.data_path("examples", "txt");
+    let module_path = format!("src/bin/{}.rs", puzzle.bin_name());
+
+    for folder in ["inputs", "examples", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(format!("data/{}/{folder}", puzzle.year())) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,9 +46,20 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
+    // NOTE: examples and their answers are extracted when the puzzle is downloaded.
+    let example_answers = InputSource::Example(None).expected_answers(puzzle);
+
     match file.write_all(
         MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .replace("%DAY_NUMBER%", &puzzle.day().into_inner().to_string())
+            .replace(
+                "%PART_ONE_ANSWER%",
+                &expected_answer(&example_answers, Part::One),
+            )
+            .replace(
+                "%PART_TWO_ANSWER%",
+                &expected_answer(&example_answers, Part::Two),
+            )
             .as_bytes(),
     ) {
         Ok(()) => {
@@ -54,26 +71,31 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
-    }
-
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    // NOTE: existing inputs and examples are kept, e.g. if the puzzle was downloaded already.
+    for (path, kind) in [(&input_path, "input"), (&example_path, "example")] {
+        match safe_create_file(path, false) {
+            Ok(_) => {
+                println!("Created empty {kind} file \"{path}\"");
+            }
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+            Err(e) => {
+                eprintln!("Failed to create {kind} file: {e}");
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if Year::from_env() == Some(puzzle.year()) {
+        println!(
+            "🎄 Type `cargo solve {}` to run your solution.",
+            puzzle.day()
+        );
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            puzzle.day(),
+            puzzle.year()
+        );
+    }
 }
@@ -1,28 +1,103 @@
-use std::process::{Command, Stdio};
+use std::{
+    path::Path,
+    process::{self, Command, Stdio},
+};
 
-use crate::template::Day;
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, BenchConfig, DaySet, InputSource, Part, PuzzleId, StoredDayStatus, Year,
+    all_days, run_multi::get_path_for_bin,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Flags of the `solve` command that are forwarded to the solution binaries.
+#[derive(Debug, Clone, Default)]
+pub struct SolveOptions {
+    pub release: bool,
+    pub dhat: bool,
+    pub alloc_stats: bool,
+    pub submit: Option<Part>,
+    pub bench: Option<BenchConfig>,
+    pub input: InputSource,
+}
+
+pub fn handle(year: Year, days: &DaySet, options: &SolveOptions) {
+    if let Some(puzzle) = days.as_single().and_then(|day| PuzzleId::new(year, day)) {
+        if !run(puzzle, options) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if options.submit.is_some() {
+        eprintln!("`--submit` can only be used when solving a single day.");
+        process::exit(1);
+    }
+
+    if matches!(options.input, InputSource::File(_)) {
+        eprintln!("`--input` can only be used when solving a single day.");
+        process::exit(1);
+    }
+
+    let days_to_run = days.resolve(year, &StoredDayStatus::read(year));
+    let mut need_space = false;
+    let mut success = true;
+
+    // NOTE: only run days that have been scaffolded, in order.
+    all_days(year)
+        .filter(|day| days_to_run.contains(day))
+        .filter_map(|day| PuzzleId::new(year, day))
+        .filter(|puzzle| Path::new(&get_path_for_bin(*puzzle)).exists())
+        .for_each(|puzzle| {
+            if need_space {
+                println!();
+            }
+            need_space = true;
 
-    if dhat {
+            println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day());
+            println!("------");
+            success &= run(puzzle, options);
+        });
+
+    if !success {
+        process::exit(1);
+    }
+}
+
+/// Runs the solution bin of a puzzle, returns whether it exited successfully.
+fn run(puzzle: PuzzleId, options: &SolveOptions) -> bool {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
+
+    if options.dhat {
         cmd_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
-        cmd_args.push("--release".to_string());
+    } else {
+        if options.release {
+            cmd_args.push("--release".to_string());
+        }
+
+        if options.alloc_stats {
+            cmd_args.extend(["--features".to_string(), "alloc-stats".to_string()]);
+        }
     }
 
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit_part) = options.submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(bench) = &options.bench {
+        cmd_args.push("--time".to_string());
+        cmd_args.extend(bench.to_args());
+    }
+
+    cmd_args.extend(options.input.to_args());
+
+    // NOTE: stdin is inherited, so that `--input -` can read from it.
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -30,5 +105,5 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    cmd.wait().unwrap().success()
 }
@@ -0,0 +1,53 @@
+use crate::template::{
+    DaySet, PuzzleId, StoredDayStatus, Timeouts, Year, all_days,
+    protocol::{Event, Step},
+    run_multi::child_commands,
+    status::{DayProgress, render_status},
+    timings::Timings,
+};
+
+/// Prints the progress of the selected days. With `run`, the scaffolded solutions are run in
+/// release mode to show which parts return an answer for the input.
+pub fn handle(year: Year, days: &DaySet, run: bool) {
+    let selected = days.resolve(year, &StoredDayStatus::read(year));
+    let timings = Timings::read_from_file(year);
+
+    let progress: Vec<DayProgress> = all_days(year)
+        .filter(|day| selected.contains(day))
+        .filter_map(|day| PuzzleId::new(year, day))
+        .map(|puzzle| {
+            let mut progress = DayProgress::read(puzzle, &timings);
+            if run && progress.scaffolded && progress.has_input {
+                progress.answers = Some(count_answers(puzzle));
+            }
+            progress
+        })
+        .collect();
+
+    for line in render_status(&progress) {
+        println!("{line}");
+    }
+}
+
+/// Runs a solution and counts the parts that returned an answer.
+fn count_answers(puzzle: PuzzleId) -> usize {
+    let events = child_commands::run_solution(puzzle, None, true, &Timeouts::default(), false)
+        .ok()
+        .flatten()
+        .map(|run| run.events)
+        .unwrap_or_default();
+
+    events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                Event::Result {
+                    step: Step::Part(_),
+                    answer: Some(_),
+                    ..
+                }
+            )
+        })
+        .count()
+}
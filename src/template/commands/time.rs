@@ -1,34 +1,52 @@
-use std::collections::HashSet;
-
-use crate::template::run_multi::run_multi;
+use crate::template::run_multi::{run_in_runner, run_multi};
+use crate::template::runner::Solution;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{BenchConfig, DaySet, StoredDayStatus, Timeouts, Year, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    days: Option<DaySet>,
+    run_all: bool,
+    store: bool,
+    bench: &BenchConfig,
+    timeouts: &Timeouts,
+    alloc_stats: bool,
+    solutions: Option<&[Solution]>,
+) {
+    // NOTE: days that may time out run in their own binaries anyway.
+    if solutions.is_none() && *timeouts == Timeouts::default() {
+        run_in_runner(true, alloc_stats);
+    }
 
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
-        },
-        |day| HashSet::from([day]),
-    );
+    let stored_timings = Timings::read_from_file(year);
+
+    let days = if run_all {
+        DaySet::all()
+    } else {
+        // when neither days nor the `--all` flag are set, filter out days that are fully benched.
+        days.unwrap_or_else(DaySet::unbenched)
+    };
+
+    let days_to_run = days.resolve(year, &StoredDayStatus::read(year));
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(
+        year,
+        &days_to_run,
+        true,
+        Some(bench),
+        timeouts,
+        alloc_stats,
+        solutions.unwrap_or_default(),
+    )
+    .unwrap();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update() {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -0,0 +1,83 @@
+use std::{
+    io::{Write, stdout},
+    process,
+    time::Duration,
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, PuzzleId,
+    aoc_client::Backend,
+    commands::{download, read, scaffold},
+    today::{
+        Clock, SystemClock, current_puzzle, format_countdown, next_unlock, retry, unlock_time,
+        wait_until,
+    },
+};
+
+/// How often the input download is attempted after a puzzle unlocks.
+const DOWNLOAD_ATTEMPTS: usize = 5;
+const DOWNLOAD_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+pub fn handle(wait: bool) {
+    run(&SystemClock, wait);
+}
+
+fn run(clock: &impl Clock, wait: bool) {
+    // fail early instead of after waiting for the unlock.
+    let backend = Backend::from_env_or_exit();
+
+    let puzzle = if wait {
+        wait_for_next_unlock(clock)
+    } else {
+        current_puzzle(clock)
+    };
+
+    let Some(puzzle) = puzzle else {
+        eprintln!(
+            "`today` command can only be run while an event is running. \
+            Please use `scaffold` with a specific day or pass `--wait`."
+        );
+        process::exit(1);
+    };
+
+    download_with_retry(clock, &backend, puzzle);
+    scaffold::handle(puzzle, false);
+    read::handle(puzzle);
+}
+
+fn wait_for_next_unlock(clock: &impl Clock) -> Option<PuzzleId> {
+    let puzzle = next_unlock(clock)?;
+    let mut stdout = stdout();
+
+    wait_until(clock, unlock_time(puzzle), |remaining| {
+        print!(
+            "\r⏳ {ANSI_BOLD}Day {}{ANSI_RESET} ({}) unlocks in {}  ",
+            puzzle.day(),
+            puzzle.year(),
+            format_countdown(remaining)
+        );
+        let _ = stdout.flush();
+    });
+
+    println!(
+        "\r🎄 {ANSI_BOLD}Day {}{ANSI_RESET} ({}) is unlocked!       ",
+        puzzle.day(),
+        puzzle.year()
+    );
+    Some(puzzle)
+}
+
+/// Inputs are sometimes not available right at unlock, so the download is retried a few times.
+fn download_with_retry(clock: &impl Clock, backend: &Backend, puzzle: PuzzleId) {
+    let result = retry(clock, DOWNLOAD_ATTEMPTS, DOWNLOAD_RETRY_DELAY, |attempt| {
+        if attempt > 1 {
+            println!("Retrying download (attempt {attempt} of {DOWNLOAD_ATTEMPTS})...");
+        }
+        download::download(backend, puzzle, false)
+    });
+
+    if let Err(e) = result {
+        eprintln!("Failed to download day {}: {e}", puzzle.day());
+        process::exit(1);
+    }
+}
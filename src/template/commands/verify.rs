@@ -0,0 +1,103 @@
+use std::process;
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Answers, DaySet, Part, PuzzleId, StoredDayStatus, Timeouts, Verdict,
+    Year, all_days,
+    protocol::{Event, Step},
+    run_multi::child_commands,
+};
+
+/// Runs every selected day that has stored answers and compares the results with them.
+/// Exits with a non-zero status code if any result does not match.
+pub fn handle(year: Year, days: &DaySet) {
+    let days_to_run = days.resolve(year, &StoredDayStatus::read(year));
+
+    let mut failures: Vec<String> = vec![];
+    let mut verified = 0;
+    let mut need_space = false;
+
+    // NOTE: only days with stored answers can be verified.
+    let puzzles = all_days(year)
+        .filter(|day| days_to_run.contains(day))
+        .filter_map(|day| PuzzleId::new(year, day))
+        .map(|puzzle| (puzzle, Answers::read(puzzle)))
+        .filter(|(_, answers)| !answers.is_empty());
+
+    for (puzzle, answers) in puzzles {
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day());
+        println!("------");
+
+        let events = child_commands::run_solution(puzzle, None, true, &Timeouts::default(), false)
+            .ok()
+            .flatten()
+            .map(|run| run.events)
+            .unwrap_or_default();
+
+        for part in Part::ALL {
+            if answers.get(part).is_none() {
+                continue;
+            }
+
+            verified += 1;
+
+            let result = events.iter().find_map(|event| match event {
+                Event::Result {
+                    step: Step::Part(p),
+                    answer,
+                    ..
+                } if *p == part => answer.as_deref(),
+                _ => None,
+            });
+
+            let error = events.iter().find_map(|event| match event {
+                Event::Error {
+                    step: Some(Step::Parse),
+                    message,
+                    ..
+                } => Some(message.as_str()),
+                Event::Error {
+                    step: Some(Step::Part(p)),
+                    message,
+                    ..
+                } if *p == part => Some(message.as_str()),
+                _ => None,
+            });
+
+            let failure = match result.map(|result| answers.verify(part, result)) {
+                Some(Verdict::Correct | Verdict::Unknown) => continue,
+                Some(Verdict::Incorrect { expected }) => {
+                    format!("expected {expected}, got {}", result.unwrap_or_default())
+                }
+                None => error.unwrap_or("no result").to_string(),
+            };
+
+            failures.push(format!("Day {} Part {part}: {failure}", puzzle.day()));
+        }
+    }
+
+    println!();
+
+    if verified == 0 {
+        println!("No stored answers to verify. Store answers with `cargo answer <day>`.");
+        return;
+    }
+
+    if failures.is_empty() {
+        println!("{ANSI_BOLD}✔ All {verified} answers match.{ANSI_RESET}");
+        return;
+    }
+
+    println!(
+        "{ANSI_BOLD}✘ {} of {verified} answers do not match:{ANSI_RESET}",
+        failures.len()
+    );
+    for failure in failures {
+        println!("  {failure}");
+    }
+    process::exit(1);
+}
@@ -0,0 +1,308 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::str::FromStr;
+
+use crate::template::timings::Timings;
+use crate::template::{Answers, Calendar, Day, Part, PuzzleId, Year, all_days};
+
+/// A selection of days, parsed from a comma-separated list of selectors.
+///
+/// Supported selectors are single days (`8`), inclusive ranges (`1-5`),
+/// open ranges (`12-`, `-3`) and the keywords `all`, `solved`, `unsolved`,
+/// `benched` and `unbenched`.
+///
+/// ```
+/// # use advent_of_code::template::DaySet;
+/// let days: DaySet = "1-5,8,12-".parse().unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet(Vec<Selector>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Selector {
+    Range(Option<Day>, Option<Day>),
+    All,
+    Solved(bool),
+    Benched(bool),
+}
+
+/// Provides the status of days that keyword selectors such as `unsolved` filter on.
+pub trait DayStatus {
+    fn is_solved(&self, day: Day) -> bool;
+    fn is_benched(&self, day: Day) -> bool;
+}
+
+/// [`DayStatus`] of a year, read from the puzzle descriptions and stored timings.
+pub struct StoredDayStatus {
+    year: Year,
+    timings: Timings,
+}
+
+impl StoredDayStatus {
+    pub fn read(year: Year) -> Self {
+        Self {
+            year,
+            timings: Timings::read_from_file(year),
+        }
+    }
+}
+
+impl DayStatus for StoredDayStatus {
+    /// A day counts as solved once its puzzle description lists an answer for both parts.
+    fn is_solved(&self, day: Day) -> bool {
+        PuzzleId::new(self.year, day)
+            .and_then(|puzzle| fs::read_to_string(puzzle.data_path("puzzles", "md")).ok())
+            .is_some_and(|description| {
+                let is_last_day = day == Calendar::of(self.year).last_day();
+                has_all_answers(&Answers::from_description(&description), is_last_day)
+            })
+    }
+
+    fn is_benched(&self, day: Day) -> bool {
+        self.timings.is_day_complete(day)
+    }
+}
+
+/// Whether a day has all its answers. The last day of an event has no second puzzle, so only its
+/// first answer is needed.
+fn has_all_answers(answers: &Answers, is_last_day: bool) -> bool {
+    answers.get(Part::One).is_some() && (is_last_day || answers.get(Part::Two).is_some())
+}
+
+impl DaySet {
+    /// A set of every day of the event.
+    pub fn all() -> Self {
+        Self(vec![Selector::All])
+    }
+
+    /// A set of the days that have not been fully benched yet.
+    pub fn unbenched() -> Self {
+        Self(vec![Selector::Benched(false)])
+    }
+
+    /// Returns the day if the set consists of exactly one explicit day.
+    pub fn as_single(&self) -> Option<Day> {
+        match self.0.as_slice() {
+            [Selector::Range(Some(start), Some(end))] if start == end => Some(*start),
+            _ => None,
+        }
+    }
+
+    /// Resolves the selected days of the event that takes place in `year`.
+    pub fn resolve(&self, year: Year, status: &impl DayStatus) -> HashSet<Day> {
+        all_days(year)
+            .filter(|day| self.0.iter().any(|selector| selector.matches(*day, status)))
+            .collect()
+    }
+}
+
+impl Selector {
+    fn matches(self, day: Day, status: &impl DayStatus) -> bool {
+        match self {
+            Selector::Range(start, end) => {
+                start.is_none_or(|start| day >= start) && end.is_none_or(|end| day <= end)
+            }
+            Selector::All => true,
+            Selector::Solved(solved) => status.is_solved(day) == solved,
+            Selector::Benched(benched) => status.is_benched(day) == benched,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let selectors = s
+            .split(',')
+            .map(|token| Selector::from_str(token.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(selectors))
+    }
+}
+
+impl FromStr for Selector {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || DaySetFromStrError::InvalidSelector(s.to_string());
+
+        let selector = match s {
+            "all" => Selector::All,
+            "solved" => Selector::Solved(true),
+            "unsolved" => Selector::Solved(false),
+            "benched" => Selector::Benched(true),
+            "unbenched" => Selector::Benched(false),
+            _ => {
+                let parse_bound = |bound: &str| -> Result<Option<Day>, Self::Err> {
+                    if bound.is_empty() {
+                        Ok(None)
+                    } else {
+                        bound.parse().map(Some).map_err(|_| err())
+                    }
+                };
+
+                match s.split_once('-') {
+                    Some(("", "")) => return Err(err()),
+                    Some((start, end)) => match (parse_bound(start)?, parse_bound(end)?) {
+                        (Some(start), Some(end)) if start > end => {
+                            return Err(DaySetFromStrError::InvertedRange(s.to_string()));
+                        }
+                        (start, end) => Selector::Range(start, end),
+                    },
+                    None => {
+                        let day = s.parse().map_err(|_| err())?;
+                        Selector::Range(Some(day), Some(day))
+                    }
+                }
+            }
+        };
+
+        Ok(selector)
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub enum DaySetFromStrError {
+    InvalidSelector(String),
+    /// A range that starts after it ends, e.g. `5-3`.
+    InvertedRange(String),
+}
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DaySetFromStrError::InvalidSelector(s) => write!(
+                f,
+                "invalid day selector `{s}`, expecting a day (`8`), a range (`1-5`, `12-`) or one \
+                of `all`, `solved`, `unsolved`, `benched`, `unbenched`"
+            ),
+            DaySetFromStrError::InvertedRange(s) => write!(
+                f,
+                "invalid day range `{s}`, its start is after its end. Did you mean `{}`?",
+                s.split('-').rev().collect::<Vec<_>>().join("-")
+            ),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{DaySet, DayStatus, has_all_answers};
+    use crate::{
+        day,
+        template::{Answers, Day},
+        year,
+    };
+
+    struct MockStatus;
+
+    impl DayStatus for MockStatus {
+        fn is_solved(&self, day: Day) -> bool {
+            day <= 3
+        }
+
+        fn is_benched(&self, day: Day) -> bool {
+            day <= 2
+        }
+    }
+
+    fn resolve(s: &str) -> Vec<u8> {
+        let mut days: Vec<u8> = s
+            .parse::<DaySet>()
+            .unwrap()
+            .resolve(year!(2025), &MockStatus)
+            .into_iter()
+            .map(Day::into_inner)
+            .collect();
+        days.sort_unstable();
+        days
+    }
+
+    #[test]
+    fn resolves_days_and_ranges() {
+        assert_eq!(resolve("4"), vec![4]);
+        assert_eq!(resolve("1-3,8"), vec![1, 2, 3, 8]);
+        assert_eq!(resolve("10-"), vec![10, 11, 12]);
+        assert_eq!(resolve("-2"), vec![1, 2]);
+        assert_eq!(resolve("1-5, 8, 12-"), vec![1, 2, 3, 4, 5, 8, 12]);
+    }
+
+    #[test]
+    fn resolves_keywords() {
+        assert_eq!(resolve("all").len(), 12);
+        assert_eq!(resolve("solved"), vec![1, 2, 3]);
+        assert_eq!(resolve("unsolved,1"), vec![1, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+        assert_eq!(resolve("benched"), vec![1, 2]);
+        assert_eq!(resolve("unbenched,-3").len(), 12);
+    }
+
+    #[test]
+    fn restricts_to_event_calendar() {
+        assert_eq!(resolve("10-20"), vec![10, 11, 12]);
+        assert_eq!(resolve("25"), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn detects_single_days() {
+        assert_eq!("8".parse::<DaySet>().unwrap().as_single(), Some(day!(8)));
+        assert_eq!("8-8".parse::<DaySet>().unwrap().as_single(), Some(day!(8)));
+        assert_eq!("8-9".parse::<DaySet>().unwrap().as_single(), None);
+        assert_eq!("all".parse::<DaySet>().unwrap().as_single(), None);
+        assert_eq!(
+            DaySet::all().resolve(year!(2024), &MockStatus),
+            (1..=25)
+                .map(|d| Day::new(d).unwrap())
+                .collect::<HashSet<_>>()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_selectors() {
+        assert!("".parse::<DaySet>().is_err());
+        assert!("-".parse::<DaySet>().is_err());
+        assert!("0".parse::<DaySet>().is_err());
+        assert!("26".parse::<DaySet>().is_err());
+        assert!("1-x".parse::<DaySet>().is_err());
+        assert!("1,,2".parse::<DaySet>().is_err());
+        assert!("unknown".parse::<DaySet>().is_err());
+    }
+
+    #[test]
+    fn rejects_inverted_ranges() {
+        let err = "1,5-3".parse::<DaySet>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid day range `5-3`, its start is after its end. Did you mean `3-5`?"
+        );
+        assert!("3-3".parse::<DaySet>().is_ok());
+    }
+
+    #[test]
+    fn needs_both_answers_except_on_last_day() {
+        let one = Answers::from_description("Your puzzle answer was `12`.");
+        let both = Answers::from_description(
+            "Your puzzle answer was `12`.\n\nYour puzzle answer was `34`.",
+        );
+
+        assert!(!has_all_answers(&Answers::from_description(""), false));
+        assert!(!has_all_answers(&Answers::from_description(""), true));
+        assert!(!has_all_answers(&one, false));
+        assert!(has_all_answers(&one, true));
+        assert!(has_all_answers(&both, false));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
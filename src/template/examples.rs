@@ -0,0 +1,310 @@
+use std::fs;
+
+use crate::template::{Answers, InputSource, Part, PuzzleId};
+
+/// Separates the description of part two from part one in puzzle descriptions.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// Marks paragraphs that list the answer of a solved part, which are not expected answers.
+const ANSWER_PREFIX: &str = "Your puzzle answer was";
+
+/// An example found in the description of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedExample {
+    pub part: Part,
+    /// The code block that follows "For example", [`None`] if the part reuses the example of
+    /// part one.
+    pub input: Option<String>,
+    /// The expected answer, guessed from the last emphasized code in the description of the part.
+    pub answer: Option<String>,
+}
+
+/// Extracts the examples and their expected answers from a puzzle description in markdown.
+///
+/// ```
+/// # use advent_of_code::template::{Part, extract_examples};
+/// let description = "For example:\n\n```\n1\n2\n```\n\nThe sum is `*3*`.\n";
+/// let examples = extract_examples(description);
+/// assert_eq!(examples[0].input.as_deref(), Some("1\n2\n"));
+/// assert_eq!(examples[0].answer.as_deref(), Some("3"));
+/// ```
+pub fn extract_examples(description: &str) -> Vec<ExtractedExample> {
+    let part_two = part_two_description(description);
+    let part_one = part_two.map_or(description, |x| &description[..description.len() - x.len()]);
+
+    let mut examples = vec![ExtractedExample {
+        part: Part::One,
+        // NOTE: the first code block of part one is almost always the example.
+        input: example_block(part_one).or_else(|| code_blocks(part_one).next().map(|x| x.1)),
+        answer: emphasized_answer(part_one),
+    }];
+
+    if let Some(part_two) = part_two {
+        examples.push(ExtractedExample {
+            part: Part::Two,
+            input: example_block(part_two),
+            answer: emphasized_answer(part_two),
+        });
+    }
+
+    examples
+}
+
+/// The description of part two, starting with its heading. [`None`] until part one is solved.
+pub fn part_two_description(description: &str) -> Option<&str> {
+    description
+        .find(PART_TWO_HEADING)
+        .map(|start| &description[start..])
+}
+
+/// The first code block that follows a paragraph mentioning "for example".
+fn example_block(section: &str) -> Option<String> {
+    code_blocks(section)
+        .find(|(before, _)| {
+            before
+                .trim_end()
+                .rsplit("\n\n")
+                .next()
+                .is_some_and(|paragraph| paragraph.to_lowercase().contains("for example"))
+        })
+        .map(|(_, block)| block)
+}
+
+/// The fenced code blocks of a section, each with the text that precedes it.
+fn code_blocks(section: &str) -> impl Iterator<Item = (&str, String)> {
+    let mut rest = section;
+    let mut offset = 0;
+
+    std::iter::from_fn(move || {
+        let start = rest.find("```")?;
+        let (_, after_fence) = rest[start..].split_once('\n')?;
+        let end = after_fence.find("```")?;
+
+        let before = &section[..offset + start];
+        let block = after_fence[..end].to_string();
+
+        let consumed = rest.len() - after_fence.len() + end + 3;
+        offset += consumed;
+        rest = &rest[consumed..];
+
+        Some((before, block))
+    })
+}
+
+/// The last emphasized code span of a section, guessed to be the expected answer.
+fn emphasized_answer(section: &str) -> Option<String> {
+    section
+        .lines()
+        .filter(|line| !line.starts_with(ANSWER_PREFIX))
+        .flat_map(emphasized_code)
+        .next_back()
+}
+
+/// The emphasized code spans of a line, e.g. `` `*42*` `` or ``*`42`*``.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let mut spans = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find(['`', '*']) {
+        let close = match rest.get(start..start + 2) {
+            Some("`*") => "*`",
+            Some("*`") => "`*",
+            _ => {
+                rest = &rest[start + 1..];
+                continue;
+            }
+        };
+
+        let value = &rest[start + 2..];
+        match value.find(close) {
+            Some(end) if end > 0 && !value[..end].contains(['`', '*']) => {
+                spans.push(value[..end].to_string());
+                rest = &value[end + 2..];
+            }
+            _ => rest = &rest[start + 1..],
+        }
+    }
+
+    spans
+}
+
+/// Extracts the examples and their expected answers from the downloaded puzzle description.
+///
+/// The example of part one is stored in `DD.txt`. Part two usually reuses it, otherwise its
+/// example is stored in `DD-2.txt`. Existing examples and expected answers are kept, as they may
+/// have been corrected by hand.
+pub fn store_examples(puzzle: PuzzleId) -> Result<(), std::io::Error> {
+    let description = fs::read_to_string(puzzle.data_path("puzzles", "md"))?;
+    let examples = extract_examples(&description);
+
+    let part_one_input = examples.first().and_then(|x| x.input.clone());
+
+    for example in examples {
+        let source = example_source(&example, part_one_input.as_deref());
+
+        let example_path = source
+            .input_path(puzzle)
+            .expect("examples have an input path");
+
+        // NOTE: `scaffold` creates empty examples, which are safe to overwrite.
+        let has_example = fs::metadata(&example_path).is_ok_and(|x| x.len() > 0);
+
+        if let Some(input) = &example.input
+            && !has_example
+        {
+            fs::write(&example_path, input)?;
+            println!(
+                "🎄 Extracted the example of part {} to \"{example_path}\".",
+                example.part
+            );
+        }
+
+        let Some(answer) = example.answer else {
+            continue;
+        };
+
+        let answers_path = source
+            .answers_path(puzzle)
+            .expect("examples have an answers path");
+
+        let mut answers = Answers::read_file(&answers_path);
+        if answers.get(example.part).is_none() {
+            answers.set(example.part, &answer);
+            answers.store_file(&answers_path)?;
+            println!(
+                "🎄 Guessed the expected answer of part {} of the {source}: {answer}",
+                example.part
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// The example file of an extracted example: part two only gets its own file if its example
+/// differs from the one of part one.
+fn example_source(example: &ExtractedExample, part_one_input: Option<&str>) -> InputSource {
+    match (&example.input, part_one_input) {
+        (Some(input), Some(part_one)) if example.part == Part::Two && input != part_one => {
+            InputSource::Example(Some(2))
+        }
+        _ => InputSource::Example(None),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ExtractedExample, example_source, extract_examples, part_two_description};
+    use crate::template::{InputSource, Part};
+
+    const DESCRIPTION: &str = "## --- Day 1: Test ---
+
+The input looks like this:
+
+```
+ignored
+```
+
+For example, suppose you have the following list:
+
+```
+3   4
+4   3
+```
+
+Pair up `*1*` and `3`. In the example above, the total is `*11*`.
+
+Your puzzle answer was `1234`.
+
+## --- Part Two ---
+
+This time, the example above has a similarity score of `*31*`.
+";
+
+    #[test]
+    fn extracts_examples() {
+        assert_eq!(
+            extract_examples(DESCRIPTION),
+            vec![
+                ExtractedExample {
+                    part: Part::One,
+                    input: Some("3   4\n4   3\n".into()),
+                    answer: Some("11".into()),
+                },
+                ExtractedExample {
+                    part: Part::Two,
+                    input: None,
+                    answer: Some("31".into()),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_separate_examples() {
+        let description = "Here:\n\n```\nfirst\n```\n\nSo `*1*`.\n\n## --- Part Two ---\n\nFor example:\n\n```\nsecond\n```\n\nNow it's *`2`*.\n";
+        let examples = extract_examples(description);
+
+        assert_eq!(examples[0].input.as_deref(), Some("first\n"));
+        assert_eq!(examples[0].answer.as_deref(), Some("1"));
+        assert_eq!(examples[1].input.as_deref(), Some("second\n"));
+        assert_eq!(examples[1].answer.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn splits_off_part_two() {
+        assert_eq!(
+            part_two_description(DESCRIPTION),
+            Some(
+                "## --- Part Two ---\n\nThis time, the example above has a similarity score of `*31*`.\n"
+            )
+        );
+        assert_eq!(part_two_description("## --- Day 1: Test ---\n"), None);
+    }
+
+    #[test]
+    fn stores_differing_example_of_part_two_separately() {
+        let example = |part, input: Option<&str>| ExtractedExample {
+            part,
+            input: input.map(String::from),
+            answer: None,
+        };
+
+        assert_eq!(
+            example_source(&example(Part::One, Some("first")), Some("first")),
+            InputSource::Example(None)
+        );
+        assert_eq!(
+            example_source(&example(Part::Two, Some("second")), Some("first")),
+            InputSource::Example(Some(2))
+        );
+        assert_eq!(
+            example_source(&example(Part::Two, Some("first")), Some("first")),
+            InputSource::Example(None)
+        );
+        assert_eq!(
+            example_source(&example(Part::Two, None), Some("first")),
+            InputSource::Example(None)
+        );
+        assert_eq!(
+            example_source(&example(Part::Two, Some("second")), None),
+            InputSource::Example(None)
+        );
+    }
+
+    #[test]
+    fn handles_descriptions_without_examples() {
+        assert_eq!(
+            extract_examples("## --- Day 1 ---\n\nNothing to see.\n"),
+            vec![ExtractedExample {
+                part: Part::One,
+                input: None,
+                answer: None,
+            }]
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,242 @@
+//! Minimal conversion of Advent of Code pages to markdown, similar to the output of aoc-cli.
+
+/// Marks paragraphs that list the answer of a solved part.
+const ANSWER_PARAGRAPH: &str = "<p>Your puzzle answer was";
+
+/// Converts the puzzle description of a day page to markdown: its `<article>`s, followed by the
+/// answers of solved parts.
+pub fn description_to_markdown(html: &str) -> String {
+    let mut fragments = vec![];
+    let mut rest = html;
+
+    loop {
+        let article = rest.find("<article").map(|start| (start, "</article>"));
+        let answer = rest.find(ANSWER_PARAGRAPH).map(|start| (start, "</p>"));
+
+        let Some((start, end_tag)) = [article, answer].into_iter().flatten().min() else {
+            break;
+        };
+
+        let Some(end) = rest[start..].find(end_tag) else {
+            break;
+        };
+
+        let end = start + end + end_tag.len();
+        fragments.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    to_markdown(&fragments.join("\n"))
+}
+
+/// Converts the response to a submitted answer, i.e. its `<article>`, to markdown.
+pub fn response_to_markdown(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| {
+            let end = html[start..].find("</article>")?;
+            Some(&html[start..start + end])
+        })
+        .unwrap_or(html);
+
+    to_markdown(article)
+}
+
+/// Converts a HTML fragment to markdown. Only handles the elements used by puzzle pages,
+/// other tags are dropped.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut hrefs = vec![];
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..start], in_pre);
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("br", _) => out.push('\n'),
+            ("a", false) => {
+                hrefs.push(attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = hrefs.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    collapse_blank_lines(&out)
+}
+
+/// Decodes the HTML entities used by puzzle pages.
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    // NOTE: whitespace between block elements is formatting of the HTML source.
+    if !in_pre && text.trim().is_empty() && text.contains('\n') {
+        return;
+    }
+    out.push_str(&decode_entities(text));
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let (_, value) = tag.split_once(&format!("{name}=\""))?;
+    let (value, _) = value.split_once('"')?;
+    Some(decode_entities(value))
+}
+
+fn collapse_blank_lines(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank_lines = 0;
+
+    for line in text.trim().lines() {
+        if line.trim().is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, description_to_markdown, response_to_markdown};
+    use crate::template::{Answers, Response, wait_time};
+
+    const DAY_PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Read <a href="/2025/about">the rules</a> &amp; count the <em>stars</em>:</p>
+<pre><code>1 &lt; 2
+<em>3</em>
+</code></pre>
+<ul>
+<li>An <code>item</code>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Again.</p>
+</article>
+<form method="post" action="2/answer"><input type="text" name="answer"/></form>
+</main>
+</body></html>"#;
+
+    #[test]
+    fn converts_descriptions() {
+        assert_eq!(
+            description_to_markdown(DAY_PAGE),
+            "## --- Day 1: Test ---\n\nRead [the rules](/2025/about) & count the *stars*:\n\n```\n1 < 2\n3\n```\n\n- An `item`.\n\nYour puzzle answer was `42`.\n\n## --- Part Two ---\n\nAgain.\n"
+        );
+
+        let answers = Answers::from_description(&description_to_markdown(DAY_PAGE));
+        assert_eq!(answers.part_1.as_deref(), Some("42"));
+        assert_eq!(answers.part_2, None);
+    }
+
+    #[test]
+    fn converts_responses() {
+        let page = r#"<main>
+<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href="/2025/day/1">[Return to Day 1]</a></p></article>
+</main>"#;
+        let text = response_to_markdown(page);
+        assert_eq!(Response::from_text(&text), Response::TooHigh);
+        assert_eq!(wait_time(&text).map(|x| x.as_secs()), Some(60));
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(decode_entities("a &lt;&gt; b"), "a <> b");
+        assert_eq!(decode_entities("&#39;&#x41;&amp;lt;"), "'A&lt;");
+        assert_eq!(decode_entities("a & b; c"), "a & b; c");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
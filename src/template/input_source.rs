@@ -0,0 +1,213 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read, stdin};
+
+use crate::template::{Answers, PuzzleId};
+
+/// The input a solution runs against, selected with `--example`, `--example-n <n>` or `--input <path>`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input at `data/<year>/inputs/DD.txt`.
+    #[default]
+    Puzzle,
+    /// The example at `data/<year>/examples/DD.txt`, or `DD-N.txt` for a numbered example.
+    Example(Option<u8>),
+    /// An arbitrary file, or stdin for `-`.
+    File(String),
+}
+
+impl InputSource {
+    /// Parses the `--example`, `--example-n <n>` and `--input <path>` flags.
+    ///
+    /// The number of an example has its own flag, as an optional value of `--example` could not be
+    /// told apart from a day that follows it.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        let example = match args.opt_value_from_str::<_, u8>("--example-n")? {
+            Some(n) => Some(Some(n)),
+            None => args.contains("--example").then_some(None),
+        };
+
+        let file: Option<String> = args.opt_value_from_str("--input")?;
+
+        match (example, file) {
+            (Some(_), Some(_)) => {
+                Err(InputSourceError("`--example` and `--input` cannot be combined".into()).into())
+            }
+            (Some(n), None) => Ok(Self::Example(n)),
+            (None, Some(path)) => Ok(Self::File(path)),
+            (None, None) => Ok(Self::Puzzle),
+        }
+    }
+
+    /// Converts the source back into flags that [`InputSource::from_args`] understands.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Example(None) => vec!["--example".into()],
+            Self::Example(Some(n)) => vec!["--example-n".into(), n.to_string()],
+            Self::File(path) => vec!["--input".into(), path.clone()],
+        }
+    }
+
+    /// Reads the input of a puzzle from this source.
+    pub fn read(&self, puzzle: PuzzleId) -> io::Result<String> {
+        match self {
+            Self::File(path) if path == "-" => {
+                let mut input = String::new();
+                stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Self::File(path) => fs::read_to_string(path),
+            source => fs::read_to_string(
+                source
+                    .input_path(puzzle)
+                    .expect("puzzle inputs and examples have an input path"),
+            ),
+        }
+    }
+
+    /// Path of the input file for puzzle inputs and examples, [`None`] for arbitrary files.
+    pub fn input_path(&self, puzzle: PuzzleId) -> Option<String> {
+        self.data_path(puzzle, "txt")
+    }
+
+    /// Path of the expected answers for this source, [`None`] for arbitrary files.
+    pub fn answers_path(&self, puzzle: PuzzleId) -> Option<String> {
+        match self {
+            Self::Puzzle => Some(puzzle.data_path("answers", "json")),
+            Self::Example(_) => self.data_path(puzzle, "json"),
+            Self::File(_) => None,
+        }
+    }
+
+    /// The known answers for this source. Empty for arbitrary files.
+    pub fn expected_answers(&self, puzzle: PuzzleId) -> Answers {
+        self.answers_path(puzzle)
+            .map(|path| Answers::read_file(&path))
+            .unwrap_or_default()
+    }
+
+    fn data_path(&self, puzzle: PuzzleId, extension: &str) -> Option<String> {
+        match self {
+            Self::Puzzle => Some(puzzle.data_path("inputs", extension)),
+            Self::Example(None) => Some(puzzle.data_path("examples", extension)),
+            Self::Example(Some(n)) => Some(format!(
+                "data/{}/examples/{}-{n}.{extension}",
+                puzzle.year(),
+                puzzle.day()
+            )),
+            Self::File(_) => None,
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Puzzle => f.write_str("puzzle input"),
+            Self::Example(None) => f.write_str("example"),
+            Self::Example(Some(n)) => write!(f, "example {n}"),
+            Self::File(path) if path == "-" => f.write_str("stdin"),
+            Self::File(path) => f.write_str(path),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`InputSource`].
+#[derive(Debug)]
+pub struct InputSourceError(String);
+
+impl Error for InputSourceError {}
+
+impl Display for InputSourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::InputSource;
+    use crate::{day, template::PuzzleId, year};
+
+    fn parse(args: &[&str]) -> (Result<InputSource, String>, Vec<String>) {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect());
+        let source = InputSource::from_args(&mut args).map_err(|e| e.to_string());
+        let rest = args
+            .finish()
+            .into_iter()
+            .map(|x| x.into_string().unwrap())
+            .collect();
+        (source, rest)
+    }
+
+    #[test]
+    fn parses_flags() {
+        assert_eq!(parse(&["3"]), (Ok(InputSource::Puzzle), vec!["3".into()]));
+        assert_eq!(
+            parse(&["3", "--example"]),
+            (Ok(InputSource::Example(None)), vec!["3".into()])
+        );
+        assert_eq!(
+            parse(&["3", "--example-n", "2"]),
+            (Ok(InputSource::Example(Some(2))), vec!["3".into()])
+        );
+        assert_eq!(
+            parse(&["--example", "3"]),
+            (Ok(InputSource::Example(None)), vec!["3".into()])
+        );
+        assert_eq!(
+            parse(&["--example", "--release", "3"]),
+            (
+                Ok(InputSource::Example(None)),
+                vec!["--release".into(), "3".into()]
+            )
+        );
+        assert_eq!(
+            parse(&["3", "--input", "-"]),
+            (Ok(InputSource::File("-".into())), vec!["3".into()])
+        );
+        assert!(parse(&["--example", "--input", "x"]).0.is_err());
+        assert!(parse(&["--example-n", "2", "--input", "x"]).0.is_err());
+        assert!(parse(&["3", "--example-n"]).0.is_err());
+    }
+
+    #[test]
+    fn roundtrips_flags() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example(None),
+            InputSource::Example(Some(2)),
+            InputSource::File("stress.txt".into()),
+        ] {
+            let args = source.to_args();
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            assert_eq!(parse(&args), (Ok(source), vec![]));
+        }
+    }
+
+    #[test]
+    fn derives_paths() {
+        let puzzle = PuzzleId::new(year!(2025), day!(3)).unwrap();
+        assert_eq!(
+            InputSource::Puzzle.answers_path(puzzle).unwrap(),
+            "data/2025/answers/03.json"
+        );
+        assert_eq!(
+            InputSource::Example(None).answers_path(puzzle).unwrap(),
+            "data/2025/examples/03.json"
+        );
+        assert_eq!(
+            InputSource::Example(Some(2)).answers_path(puzzle).unwrap(),
+            "data/2025/examples/03-2.json"
+        );
+        assert_eq!(InputSource::File("-".into()).answers_path(puzzle), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,396 @@
+use std::{collections::BTreeMap, collections::HashMap, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Calendar, Day, Part, PuzzleId, Year, table::align_columns};
+
+/// When a member earned the stars of a day, as unix timestamps.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayCompletion {
+    pub part_1: Option<u64>,
+    pub part_2: Option<u64>,
+}
+
+impl DayCompletion {
+    pub fn get(&self, part: Part) -> Option<u64> {
+        match part {
+            Part::One => self.part_1,
+            Part::Two => self.part_2,
+        }
+    }
+
+    pub fn stars(&self) -> usize {
+        Part::ALL
+            .into_iter()
+            .filter(|x| self.get(*x).is_some())
+            .count()
+    }
+}
+
+/// A member of a private leaderboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// [`None`] for anonymous users.
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    pub last_star_ts: u64,
+    pub completions: BTreeMap<Day, DayCompletion>,
+}
+
+impl Member {
+    /// The name of the member, as shown on the website.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+}
+
+/// A private leaderboard, as exported by the "API" link of its page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: Year,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// The members ordered by local score, then by stars and by who got their last star first.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+}
+
+/// Formats the time it took to earn a star as `HH:MM:SS`, prefixed by the number of days if needed.
+pub fn format_elapsed(secs: u64) -> String {
+    let (days, hours, minutes, seconds) =
+        (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Renders the members of a leaderboard with their local score, stars and the stars of each day:
+/// `*` for both parts, `+` for part one only and `.` for none.
+pub fn render_ranking(leaderboard: &Leaderboard) -> Vec<String> {
+    let calendar = Calendar::of(leaderboard.year);
+    let mut rows = vec![vec![
+        String::new(),
+        "Score".into(),
+        "Stars".into(),
+        "Days".into(),
+        "Name".into(),
+    ]];
+
+    for (rank, member) in leaderboard.ranking().into_iter().enumerate() {
+        let days: String = calendar
+            .days()
+            .map(
+                |day| match member.completions.get(&day).map(DayCompletion::stars) {
+                    Some(2) => '*',
+                    Some(1) => '+',
+                    _ => '.',
+                },
+            )
+            .collect();
+
+        rows.push(vec![
+            format!("{})", rank + 1),
+            member.local_score.to_string(),
+            member.stars.to_string(),
+            days,
+            member.display_name(),
+        ]);
+    }
+
+    align_columns(&rows)
+}
+
+/// Renders the time it took members to complete the parts of a day, ordered by completion.
+pub fn render_day(leaderboard: &Leaderboard, puzzle: PuzzleId) -> Vec<String> {
+    let unlock = Calendar::of(puzzle.year()).unlock_timestamp(puzzle.day());
+    let elapsed = |ts: Option<u64>| ts.map(|ts| ts.saturating_sub(unlock));
+    let format = |secs: Option<u64>| secs.map_or("-".into(), format_elapsed);
+
+    let mut completions: Vec<(&Member, Option<u64>, Option<u64>)> = leaderboard
+        .members
+        .iter()
+        .filter_map(|member| {
+            let completion = member.completions.get(&puzzle.day())?;
+            Some((
+                member,
+                elapsed(completion.part_1),
+                elapsed(completion.part_2),
+            ))
+        })
+        .filter(|(_, part_1, _)| part_1.is_some())
+        .collect();
+
+    // NOTE: members that solved both parts come first, as on the website.
+    completions
+        .sort_by_key(|(member, part_1, part_2)| (part_2.is_none(), *part_2, *part_1, member.id));
+
+    let mut rows = vec![vec![
+        String::new(),
+        "Part 1".into(),
+        "Part 2".into(),
+        "Delta".into(),
+        "Name".into(),
+    ]];
+
+    for (rank, (member, part_1, part_2)) in completions.into_iter().enumerate() {
+        let delta = part_1.zip(part_2).map(|(a, b)| b.saturating_sub(a));
+        rows.push(vec![
+            format!("{})", rank + 1),
+            format(part_1),
+            format(part_2),
+            format(delta),
+            member.display_name(),
+        ]);
+    }
+
+    align_columns(&rows)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let year: Year = json
+            .get("event")
+            .and_then(|x| x.get::<String>())
+            .and_then(|x| x.parse().ok())
+            .ok_or("expected `json.event` to be a year.")?;
+
+        let members = json
+            .get("members")
+            .and_then(|x| x.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.members` to be an object.")?;
+
+        let members = members
+            .iter()
+            .map(|(id, member)| parse_member(id, member))
+            .collect::<Result<_, String>>()?;
+
+        Ok(Leaderboard { year, members })
+    }
+}
+
+fn parse_member(id: &str, value: &JsonValue) -> Result<Member, String> {
+    let map = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or(format!("expected `json.members.{id}` to be an object."))?;
+
+    let number = |key: &str| {
+        map.get(key)
+            .and_then(|x| x.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or(format!(
+                "expected `json.members.{id}.{key}` to be a number."
+            ))
+    };
+
+    let name = match map.get("name") {
+        None | Some(JsonValue::Null) => None,
+        Some(name) => Some(
+            name.get::<String>()
+                .cloned()
+                .ok_or(format!("expected `json.members.{id}.name` to be a string."))?,
+        ),
+    };
+
+    let days = map
+        .get("completion_day_level")
+        .and_then(|x| x.get::<HashMap<String, JsonValue>>())
+        .ok_or(format!(
+            "expected `json.members.{id}.completion_day_level` to be an object."
+        ))?;
+
+    let completions = days
+        .iter()
+        .map(|(day, parts)| {
+            let path = format!("json.members.{id}.completion_day_level.{day}");
+            let day: Day = day
+                .parse()
+                .map_err(|_| format!("expected `{path}` to be a day."))?;
+
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("expected `{path}` to be an object."))?;
+
+            let star = |part: &str| match parts.get(part) {
+                None => Ok(None),
+                Some(star) => star
+                    .get::<HashMap<String, JsonValue>>()
+                    .and_then(|x| x.get("get_star_ts"))
+                    .and_then(|x| x.get::<f64>())
+                    .map(|x| Some(*x as u64))
+                    .ok_or(format!(
+                        "expected `{path}.{part}.get_star_ts` to be a number."
+                    )),
+            };
+
+            Ok((
+                day,
+                DayCompletion {
+                    part_1: star("1")?,
+                    part_2: star("2")?,
+                },
+            ))
+        })
+        .collect::<Result<_, String>>()?;
+
+    Ok(Member {
+        id: number("id")?,
+        name,
+        local_score: number("local_score")?,
+        stars: number("stars")?,
+        last_star_ts: number("last_star_ts").unwrap_or_default(),
+        completions,
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Leaderboard, format_elapsed, render_day, render_ranking};
+    use crate::{day, template::PuzzleId, year};
+
+    // NOTE: day 1 of 2025 unlocked at 1764565200, day 2 at 1764651600.
+    const EXPORT: &str = r#"{
+        "event": "2025",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1,
+                "name": "Alice",
+                "local_score": 10,
+                "stars": 3,
+                "global_score": 0,
+                "last_star_ts": 1764652200,
+                "completion_day_level": {
+                    "1": {
+                        "1": { "get_star_ts": 1764565500, "star_index": 0 },
+                        "2": { "get_star_ts": 1764566100, "star_index": 1 }
+                    },
+                    "2": {
+                        "1": { "get_star_ts": 1764652200, "star_index": 2 }
+                    }
+                }
+            },
+            "2": {
+                "id": 2,
+                "name": null,
+                "local_score": 10,
+                "stars": 3,
+                "global_score": 0,
+                "last_star_ts": 1764740000,
+                "completion_day_level": {
+                    "1": {
+                        "1": { "get_star_ts": 1764565380, "star_index": 0 },
+                        "2": { "get_star_ts": 1764655200, "star_index": 1 }
+                    },
+                    "2": {
+                        "1": { "get_star_ts": 1764740000, "star_index": 2 }
+                    }
+                }
+            },
+            "3": {
+                "id": 3,
+                "name": "Carol",
+                "local_score": 0,
+                "stars": 0,
+                "global_score": 0,
+                "last_star_ts": 0,
+                "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::try_from(EXPORT.to_string()).unwrap()
+    }
+
+    fn puzzle(day: u8) -> PuzzleId {
+        PuzzleId::new(year!(2025), crate::template::Day::new(day).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn parses_exports() {
+        let leaderboard = leaderboard();
+        assert_eq!(leaderboard.year, year!(2025));
+        assert_eq!(leaderboard.members.len(), 3);
+
+        let ranking = leaderboard.ranking();
+        assert_eq!(ranking[0].display_name(), "Alice");
+        assert_eq!(ranking[1].display_name(), "(anonymous user #2)");
+        assert_eq!(ranking[0].completions[&day!(1)].part_2, Some(1_764_566_100));
+        assert_eq!(ranking[0].completions[&day!(2)].part_2, None);
+
+        assert!(Leaderboard::try_from("[]".to_string()).is_err());
+        assert!(Leaderboard::try_from(r#"{"event":"2025"}"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn formats_elapsed_time() {
+        assert_eq!(format_elapsed(0), "00:00:00");
+        assert_eq!(format_elapsed(3_723), "01:02:03");
+        assert_eq!(format_elapsed(90_000), "1d 01:00:00");
+    }
+
+    #[test]
+    fn renders_ranking() {
+        assert_eq!(
+            render_ranking(&leaderboard()),
+            vec![
+                "    Score  Stars  Days          Name",
+                "1)  10     3      *+..........  Alice",
+                "2)  10     3      *+..........  (anonymous user #2)",
+                "3)  0      0      ............  Carol",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_days() {
+        assert_eq!(
+            render_day(&leaderboard(), puzzle(1)),
+            vec![
+                "    Part 1    Part 2       Delta        Name",
+                "1)  00:05:00  00:15:00     00:10:00     Alice",
+                "2)  00:03:00  1d 01:00:00  1d 00:57:00  (anonymous user #2)",
+            ]
+        );
+        assert_eq!(
+            render_day(&leaderboard(), puzzle(2)),
+            vec![
+                "    Part 1       Part 2  Delta  Name",
+                "1)  00:10:00     -       -      Alice",
+                "2)  1d 00:33:20  -       -      (anonymous user #2)",
+            ]
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -1,15 +1,54 @@
 use std::{env, fs};
 
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod protocol;
 pub mod runner;
+#[cfg(feature = "today")]
+pub mod today;
 
+pub use alloc_stats::*;
+pub use answers::*;
+pub use bench_config::*;
+pub use calendar::*;
+pub use checksums::*;
 pub use day::*;
+pub use day_set::*;
+pub use examples::*;
+pub use input_source::*;
+pub use leaderboard::*;
+pub use outcome::*;
+pub use part::*;
+pub use puzzle::*;
+pub use stats::*;
+pub use submissions::*;
+pub use timeouts::*;
+pub use year::*;
 
+mod alloc_stats;
+mod answers;
+mod bench_config;
+mod calendar;
+mod checksums;
 mod day;
+mod day_set;
+mod examples;
+mod html;
+mod input_source;
+mod leaderboard;
+mod outcome;
+mod part;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod stats;
+mod status;
+mod submissions;
+mod table;
+mod timeouts;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -17,52 +56,102 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(puzzle.data_path(folder, "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: Part) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(puzzle.year().to_string())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day()));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The year is derived from the file name of the solution, e.g. `2025_01.rs`.
+///
+/// The optional `parse = <function>` parameter parses the input once before running the parts, which
+/// then receive a reference to the parsed input instead of the raw input. Parsing is timed separately.
+///
+/// The optional, last parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// ```ignore
+/// advent_of_code::solution!(4);
+/// advent_of_code::solution!(4, 1);
+/// advent_of_code::solution!(4, parse = parse_grid);
+/// advent_of_code::solution!(4, parse = parse_grid, 2);
+/// ```
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [] [part_one, One] [part_two, Two]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [] [part_one, One]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [] [part_two, Two]);
+    };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl $day, [$parse] [part_one, One] [part_two, Two]);
+    };
+    ($day:expr, parse = $parse:expr, 1) => {
+        $crate::solution!(@impl $day, [$parse] [part_one, One]);
     };
+    ($day:expr, parse = $parse:expr, 2) => {
+        $crate::solution!(@impl $day, [$parse] [part_two, Two]);
+    };
+
+    (@impl $day:expr, [$($parse:expr)?] $( [$func:expr, $part:ident] )*) => {
+        /// The current year.
+        const YEAR: $crate::template::Year = const {
+            $crate::template::Year::from_source_path(file!())
+                .expect("invalid file name, expecting `<year>_<day>.rs`, e.g. `2025_01.rs`")
+        };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId = const {
+            $crate::template::PuzzleId::new(YEAR, DAY)
+                .expect("invalid day number, the day is not part of this year's event")
+        };
+
+        // NOTE: the runner binary installs the allocator once for all solutions it compiles in.
+        #[cfg(not(feature = "solutions"))]
+        $crate::global_allocator!();
+
+        /// The steps of the solution, run by `main` or in-process by the `solutions` feature.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::runner::Solution = $crate::template::runner::Solution {
+            puzzle: PUZZLE,
+            solve: |runner: &mut $crate::template::runner::Runner| {
+                let Some(input) = runner.read_input() else {
+                    return;
+                };
+                $( let Some(input) = runner.run_parse($parse, input.as_str()) else {
+                    return;
+                }; )?
+                $( runner.run_part($func, &input, $crate::template::Part::$part); )*
+            },
+        };
 
+        #[allow(dead_code)]
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let mut runner = $crate::template::runner::Runner::from_args(PUZZLE);
+            (SOLUTION.solve)(&mut runner);
+            runner.finish();
         }
     };
 }
@@ -0,0 +1,170 @@
+use std::fmt::Display;
+
+/// The outcome of a solution part, as printed and reported by the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    /// The part did not find an answer, e.g. it returned [`None`].
+    NoAnswer,
+    /// The part returned an error.
+    Error(String),
+    /// The part returned [`NotImplemented`].
+    NotImplemented,
+}
+
+/// Marks a part that has not been implemented yet, as returned by newly scaffolded solutions.
+///
+/// Unlike [`None`], which is reported as a part without an answer, the runner skips parts that
+/// are not implemented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotImplemented;
+
+/// Wraps an answer of any type that implements [`Display`], e.g. a custom answer type.
+///
+/// ```
+/// # use advent_of_code::template::{Answer, Outcome, ToOutcome};
+/// assert_eq!(Answer(1.5).outcome(), Outcome::Answer("1.5".into()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Answer<T: Display>(pub T);
+
+/// Return types of solution parts.
+///
+/// Implemented for `Option<T>` and `Result<T, E>` of answers that implement [`Display`], for
+/// common answer types such as numbers and strings, and for [`NotImplemented`]. Other types that
+/// implement [`Display`] are not answers on their own, return them wrapped in [`Answer`].
+///
+/// ```
+/// # use advent_of_code::template::{NotImplemented, Outcome, ToOutcome};
+/// assert_eq!(42.outcome(), Outcome::Answer("42".into()));
+/// assert_eq!(None::<u64>.outcome(), Outcome::NoAnswer);
+/// assert_eq!(Err::<u64, _>("no path").outcome(), Outcome::Error("no path".into()));
+/// assert_eq!(NotImplemented.outcome(), Outcome::NotImplemented);
+/// ```
+pub trait ToOutcome {
+    fn outcome(&self) -> Outcome;
+}
+
+impl Outcome {
+    /// Whether the part finished, i.e. it is worth benching.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Outcome::Answer(_) | Outcome::NoAnswer)
+    }
+
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            Outcome::Answer(answer) => Some(answer),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Display> ToOutcome for Option<T> {
+    fn outcome(&self) -> Outcome {
+        match self {
+            Some(answer) => Outcome::Answer(answer.to_string()),
+            None => Outcome::NoAnswer,
+        }
+    }
+}
+
+impl<T: Display, E: Display> ToOutcome for Result<T, E> {
+    fn outcome(&self) -> Outcome {
+        match self {
+            Ok(answer) => Outcome::Answer(answer.to_string()),
+            Err(e) => Outcome::Error(e.to_string()),
+        }
+    }
+}
+
+impl<T: Display> ToOutcome for Answer<T> {
+    fn outcome(&self) -> Outcome {
+        Outcome::Answer(self.0.to_string())
+    }
+}
+
+impl ToOutcome for NotImplemented {
+    fn outcome(&self) -> Outcome {
+        Outcome::NotImplemented
+    }
+}
+
+macro_rules! impl_answer {
+    ($($ty:ty),*) => {
+        $(
+            impl ToOutcome for $ty {
+                fn outcome(&self) -> Outcome {
+                    Outcome::Answer(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str
+);
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fmt::Display;
+
+    use super::{Answer, NotImplemented, Outcome, ToOutcome};
+
+    #[test]
+    fn converts_options() {
+        assert_eq!(Some(42).outcome(), Outcome::Answer("42".into()));
+        assert_eq!(Some("abc").outcome(), Outcome::Answer("abc".into()));
+        assert_eq!(None::<u64>.outcome(), Outcome::NoAnswer);
+    }
+
+    #[test]
+    fn converts_results() {
+        assert_eq!(Ok::<_, String>(42).outcome(), Outcome::Answer("42".into()));
+        assert_eq!(
+            Err::<u64, _>("no path to the exit").outcome(),
+            Outcome::Error("no path to the exit".into())
+        );
+    }
+
+    #[test]
+    fn converts_answers() {
+        assert_eq!(42_u64.outcome(), Outcome::Answer("42".into()));
+        assert_eq!((-1_i32).outcome(), Outcome::Answer("-1".into()));
+        assert_eq!("abc".outcome(), Outcome::Answer("abc".into()));
+        assert_eq!(
+            String::from("a\nb").outcome(),
+            Outcome::Answer("a\nb".into())
+        );
+        assert_eq!(NotImplemented.outcome(), Outcome::NotImplemented);
+    }
+
+    #[test]
+    fn converts_wrapped_answers() {
+        struct Position(i32, i32);
+
+        impl Display for Position {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{},{}", self.0, self.1)
+            }
+        }
+
+        assert_eq!(
+            Answer(Position(3, -1)).outcome(),
+            Outcome::Answer("3,-1".into())
+        );
+        assert_eq!(Answer(1.5).outcome(), Outcome::Answer("1.5".into()));
+    }
+
+    #[test]
+    fn marks_finished_outcomes() {
+        assert!(Outcome::Answer("42".into()).is_finished());
+        assert!(Outcome::NoAnswer.is_finished());
+        assert!(!Outcome::Error("oops".into()).is_finished());
+        assert!(!Outcome::NotImplemented.is_finished());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
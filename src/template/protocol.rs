@@ -0,0 +1,391 @@
+/// Machine-readable channel between solution binaries and the runner.
+///
+/// When invoked with [`FLAG`], solution binaries print one JSON object per line for every result
+/// in addition to their human-readable output. Protocol lines are marked by an `"aoc"` key, all
+/// other lines are regular output.
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use tinyjson::JsonValue;
+
+use crate::template::{AllocStats, BenchStats, Part};
+
+/// Flag that enables the protocol in solution binaries.
+pub const FLAG: &str = "--json-lines";
+
+/// A step of a solution that produces a result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+/// The kind of failure reported by an [`Event::Error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The step panicked.
+    Panic,
+    /// The step returned an error.
+    Failed,
+    /// The binary was invoked with invalid arguments.
+    InvalidArguments,
+    /// The step did not finish in time and the binary was killed by the runner.
+    Timeout,
+}
+
+/// An event emitted by a solution binary.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// A step started, so that the runner can time it out.
+    Start { step: Step },
+    /// A step finished. `answer` is [`None`] for the parse step and for parts without an answer.
+    Result {
+        step: Step,
+        answer: Option<String>,
+        duration: Duration,
+        stats: Option<BenchStats>,
+        /// Heap usage of the first execution, only collected with the `alloc-stats` feature.
+        allocs: Option<AllocStats>,
+    },
+    /// A step, or the binary as a whole if `step` is [`None`], failed.
+    Error {
+        step: Option<Step>,
+        kind: ErrorKind,
+        message: String,
+    },
+}
+
+/// Whether the current process was asked to emit protocol lines.
+pub fn is_enabled() -> bool {
+    env::args().any(|x| x == FLAG)
+}
+
+/// Prints the event as a protocol line if the protocol is enabled.
+pub fn emit(event: &Event) {
+    if is_enabled() {
+        println!("{}", event.to_line());
+    }
+}
+
+impl Event {
+    /// Number of times the step was executed to measure its duration.
+    pub fn samples(&self) -> u128 {
+        match self {
+            Event::Result {
+                stats: Some(stats), ..
+            } => stats.samples,
+            _ => 1,
+        }
+    }
+
+    pub fn to_line(&self) -> String {
+        // NOTE: serializing a map of strings, numbers and objects does not fail.
+        JsonValue::from(self).stringify().unwrap()
+    }
+
+    /// Parses a line of output, returns [`None`] if the line is not a protocol line.
+    pub fn from_line(line: &str) -> Option<Result<Self, String>> {
+        let json = JsonValue::from_str(line.trim()).ok()?;
+        let map = json.get::<HashMap<String, JsonValue>>()?;
+        map.contains_key("aoc").then(|| Event::try_from(&json))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => f.write_str("parse"),
+            Step::Part(part) => write!(f, "{part}"),
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            s => s
+                .parse()
+                .map(Step::Part)
+                .map_err(|_| format!("Unknown step `{s}`.")),
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::Panic => f.write_str("panic"),
+            ErrorKind::Failed => f.write_str("failed"),
+            ErrorKind::InvalidArguments => f.write_str("invalid_arguments"),
+            ErrorKind::Timeout => f.write_str("timeout"),
+        }
+    }
+}
+
+impl FromStr for ErrorKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "panic" => Ok(ErrorKind::Panic),
+            "failed" => Ok(ErrorKind::Failed),
+            "invalid_arguments" => Ok(ErrorKind::InvalidArguments),
+            "timeout" => Ok(ErrorKind::Timeout),
+            s => Err(format!("Unknown error kind `{s}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Event> for JsonValue {
+    fn from(value: &Event) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        match value {
+            Event::Start { step } => {
+                map.insert("aoc".into(), JsonValue::String("start".into()));
+                map.insert("step".into(), JsonValue::String(step.to_string()));
+            }
+            Event::Result {
+                step,
+                answer,
+                duration,
+                stats,
+                allocs,
+            } => {
+                map.insert("aoc".into(), JsonValue::String("result".into()));
+                map.insert("step".into(), JsonValue::String(step.to_string()));
+                map.insert(
+                    "answer".into(),
+                    answer.clone().map_or(JsonValue::Null, JsonValue::String),
+                );
+                map.insert(
+                    "nanos".into(),
+                    JsonValue::Number(duration.as_nanos() as f64),
+                );
+                map.insert("samples".into(), JsonValue::Number(value.samples() as f64));
+                if let Some(stats) = stats {
+                    map.insert("stats".into(), JsonValue::from(stats));
+                }
+                if let Some(allocs) = allocs {
+                    map.insert("allocs".into(), JsonValue::from(allocs));
+                }
+            }
+            Event::Error {
+                step,
+                kind,
+                message,
+            } => {
+                map.insert("aoc".into(), JsonValue::String("error".into()));
+                map.insert("kind".into(), JsonValue::String(kind.to_string()));
+                map.insert(
+                    "step".into(),
+                    step.map_or(JsonValue::Null, |step| JsonValue::String(step.to_string())),
+                );
+                map.insert("message".into(), JsonValue::String(message.clone()));
+            }
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Event {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected event to be a JSON object.")?;
+
+        let kind = json
+            .get("aoc")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected event.aoc to be a string.")?;
+
+        let step = json
+            .get("step")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected event.step to be null or string.")?
+            .map(|step| step.parse::<Step>())
+            .transpose()?;
+
+        match kind.as_str() {
+            "start" => Ok(Event::Start {
+                step: step.ok_or("Expected start event to have a step.")?,
+            }),
+            "result" => {
+                let step = step.ok_or("Expected result event to have a step.")?;
+
+                let answer = json
+                    .get("answer")
+                    .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                    .ok_or("Expected event.answer to be null or string.")?;
+
+                let nanos = json
+                    .get("nanos")
+                    .and_then(|v| v.get::<f64>().copied())
+                    .ok_or("Expected event.nanos to be a number.")?;
+
+                let stats = json.get("stats").map(BenchStats::try_from).transpose()?;
+                let allocs = json.get("allocs").map(AllocStats::try_from).transpose()?;
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                Ok(Event::Result {
+                    step,
+                    answer: answer.cloned(),
+                    duration: Duration::from_nanos(nanos as u64),
+                    stats,
+                    allocs,
+                })
+            }
+            "error" => {
+                let kind = json
+                    .get("kind")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected event.kind to be a string.")?
+                    .parse()?;
+
+                let message = json
+                    .get("message")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected event.message to be a string.")?;
+
+                Ok(Event::Error {
+                    step,
+                    kind,
+                    message: message.clone(),
+                })
+            }
+            kind => Err(format!("Unknown event `{kind}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{ErrorKind, Event, Step};
+    use crate::template::{AllocStats, BenchStats, Part};
+
+    #[test]
+    fn roundtrips_results() {
+        let events = [
+            Event::Start { step: Step::Parse },
+            Event::Result {
+                step: Step::Parse,
+                answer: None,
+                duration: Duration::from_nanos(1234),
+                stats: None,
+                allocs: Some(AllocStats {
+                    peak_bytes: 4096,
+                    allocations: 3,
+                }),
+            },
+            Event::Result {
+                step: Step::Part(Part::One),
+                answer: Some("42 (74.13ns @ 100000 samples)\nline".into()),
+                duration: Duration::from_nanos(74),
+                stats: Some(BenchStats::from_samples(&[
+                    Duration::from_nanos(70),
+                    Duration::from_nanos(74),
+                    Duration::from_nanos(80),
+                ])),
+                allocs: None,
+            },
+            Event::Result {
+                step: Step::Part(Part::Two),
+                answer: None,
+                duration: Duration::from_secs(2),
+                stats: None,
+                allocs: None,
+            },
+        ];
+
+        for event in events {
+            let line = event.to_line();
+            assert!(!line.contains('\n'));
+            assert_eq!(Event::from_line(&line), Some(Ok(event)));
+        }
+    }
+
+    #[test]
+    fn roundtrips_errors() {
+        let event = Event::Error {
+            step: Some(Step::Part(Part::Two)),
+            kind: ErrorKind::Panic,
+            message: "attempt to subtract with overflow".into(),
+        };
+        assert_eq!(Event::from_line(&event.to_line()), Some(Ok(event)));
+
+        let event = Event::Error {
+            step: Some(Step::Part(Part::One)),
+            kind: ErrorKind::Failed,
+            message: "no path to the exit".into(),
+        };
+        assert_eq!(Event::from_line(&event.to_line()), Some(Ok(event)));
+
+        let event = Event::Error {
+            step: None,
+            kind: ErrorKind::InvalidArguments,
+            message: "invalid options".into(),
+        };
+        assert_eq!(Event::from_line(&event.to_line()), Some(Ok(event)));
+
+        let event = Event::Error {
+            step: Some(Step::Parse),
+            kind: ErrorKind::Timeout,
+            message: "timed out after 10.0s".into(),
+        };
+        assert_eq!(Event::from_line(&event.to_line()), Some(Ok(event)));
+    }
+
+    #[test]
+    fn ignores_regular_output() {
+        assert_eq!(
+            Event::from_line("Part 1: 0 (74.13ns @ 100000 samples)"),
+            None
+        );
+        assert_eq!(Event::from_line(""), None);
+        assert_eq!(Event::from_line("42"), None);
+        assert_eq!(Event::from_line(r#"{ "answer": "42" }"#), None);
+    }
+
+    #[test]
+    fn rejects_malformed_events() {
+        assert!(matches!(
+            Event::from_line(r#"{ "aoc": "result" }"#),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            Event::from_line(r#"{ "aoc": "start", "step": null }"#),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            Event::from_line(r#"{ "aoc": "unknown" }"#),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            Event::from_line(r#"{ "aoc": "result", "step": "3", "answer": null, "nanos": 1 }"#),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            Event::from_line(r#"{ "aoc": "error", "step": null, "kind": "oops", "message": "" }"#),
+            Some(Err(_))
+        ));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
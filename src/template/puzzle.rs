@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+use crate::template::{Calendar, Day, Year};
+
+/// Identifies a single puzzle by its [`Year`] and a [`Day`] of that year's [`Calendar`].
+///
+/// # Display
+/// This value displays as `<year>/<day>`.
+///
+/// ```
+/// # use advent_of_code::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2025).unwrap(), Day::new(8).unwrap()).unwrap();
+/// assert_eq!(puzzle.to_string(), "2025/08");
+/// assert_eq!(puzzle.bin_name(), "2025_08");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    year: Year,
+    day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if `day` is part of the event calendar of `year`,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: Year, day: Day) -> Option<Self> {
+        if !Calendar::of(year).contains(day) {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    pub fn year(self) -> Year {
+        self.year
+    }
+
+    pub fn day(self) -> Day {
+        self.day
+    }
+
+    /// The name of the binary that contains the solution for this puzzle, e.g. `2025_01`.
+    pub fn bin_name(self) -> String {
+        format!("{}_{}", self.year, self.day)
+    }
+
+    /// The path of a data file for this puzzle, e.g. `data/2025/inputs/01.txt`.
+    pub fn data_path(self, folder: &str, extension: &str) -> String {
+        format!("data/{}/{folder}/{}.{extension}", self.year, self.day)
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the current puzzle if today is a day of this year's event calendar, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        crate::template::today::current_puzzle(&crate::template::today::SystemClock)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
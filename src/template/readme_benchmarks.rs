@@ -2,8 +2,9 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
+use crate::template::run_multi::get_path_for_bin;
 use crate::template::timings::Timings;
+use crate::template::{PuzzleId, Year, format_bytes};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -25,11 +26,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -52,47 +48,86 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(prefix: &str, year: Year, timings: Timings) -> Vec<String> {
+    let header = format!("{prefix} {year} Benchmarks");
+    let total_millis = timings.total_millis();
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // NOTE: heap usage is only shown if some day was timed with the `alloc-stats` feature.
+    let show_allocs = timings.data.iter().any(|timing| timing.allocs().is_some());
+
+    let mut lines: Vec<String> = vec![header, String::new()];
+
+    if show_allocs {
+        lines.push("| Day | Parse | Part 1 | Part 2 | Peak memory | Allocations |".into());
+        lines.push("| :---: | :---: | :---: | :---: | :---: | :---: |".into());
+    } else {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+        // NOTE: timings of days outside of the event are stale, they have no solution to link.
+        let Some(puzzle) = PuzzleId::new(year, timing.day) else {
+            continue;
+        };
+        let path = get_path_for_bin(puzzle);
+        // NOTE: parts of a day that timed out have no timing, as they did not finish.
+        let missing_part = if timing.timed_out { "timed out" } else { "-" };
+        let allocs = timing.allocs();
+
+        let mut line = format!(
+            "| [Day {}]({}) | `{}` | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            timing.parse.unwrap_or_else(|| "-".into()),
+            timing.part_1.unwrap_or_else(|| missing_part.into()),
+            timing.part_2.unwrap_or_else(|| missing_part.into())
+        );
+
+        if show_allocs {
+            line.push_str(&format!(
+                " `{}` | `{}` |",
+                allocs.map_or_else(|| "-".into(), |allocs| format_bytes(allocs.peak_bytes)),
+                allocs.map_or_else(|| "-".into(), |allocs| allocs.allocations.to_string())
+            ));
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
 
+    lines
+}
+
+fn construct_tables(prefix: &str, years: Vec<(Year, Timings)>) -> String {
+    let mut lines: Vec<String> = vec![MARKER.into()];
+
+    // NOTE: show the most recent year first.
+    for (i, (year, timings)) in years.into_iter().rev().enumerate() {
+        if i > 0 {
+            lines.push(String::new());
+        }
+        lines.append(&mut construct_table(prefix, year, timings));
+    }
+
+    lines.push(MARKER.into());
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(s: &mut String, years: Vec<(Year, Timings)>) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_tables("##", years);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Updates the readme with the stored timings of every year.
+pub fn update() -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, Timings::read_all_years())?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -100,28 +135,36 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day, template::AllocStats, template::Year, template::timings::Timing,
+        template::timings::Timings, year,
+    };
+
+    fn get_mock_years() -> Vec<(Year, Timings)> {
+        vec![(year!(2025), get_mock_timings())]
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    total_nanos: 3e+7,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    total_nanos: 7e+7,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
+                    parse: Some("5ms".into()),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    total_nanos: 9e+7,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -131,47 +174,89 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_years()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_years()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, get_mock_years()).unwrap();
+        assert_eq!(s.contains("## 2025 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_years()).unwrap();
+        update_content(&mut s, get_mock_years()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        assert_eq!(
+            s.matches("## 2025 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn marks_timed_out_parts() {
+        let mut timings = get_mock_timings();
+        timings.data[1].part_2 = None;
+        timings.data[1].timed_out = true;
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, vec![(year!(2025), timings)]).unwrap();
+        assert!(s.contains("| [Day 2](./src/bin/2025_02.rs) | `-` | `30ms` | `timed out` |"));
+        assert!(s.contains("| [Day 1](./src/bin/2025_01.rs) | `-` | `10ms` | `20ms` |"));
+    }
+
+    #[test]
+    fn shows_allocs_if_present() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_allocs = Some(AllocStats {
+            peak_bytes: 2048,
+            allocations: 3,
+        });
+        timings.data[0].part_2_allocs = Some(AllocStats {
+            peak_bytes: 1024,
+            allocations: 4,
+        });
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, vec![(year!(2025), timings)]).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 | Peak memory | Allocations |"));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2025_01.rs) | `-` | `10ms` | `20ms` | `2.0 KiB` | `7` |"
+        ));
+        assert!(
+            s.contains("| [Day 2](./src/bin/2025_02.rs) | `-` | `30ms` | `40ms` | `-` | `-` |")
+        );
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, get_mock_years()).unwrap();
+        assert!(!s.contains("Peak memory"));
     }
 
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_years()).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "## 2025 Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2025_01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2025_02.rs) | `-` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2025_04.rs) | `5ms` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +265,19 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_for_multiple_years() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let years = vec![
+            (year!(2024), get_mock_timings()),
+            (year!(2025), get_mock_timings()),
+        ];
+        update_content(&mut s, years).unwrap();
+        let pos_2024 = s.find("## 2024 Benchmarks").unwrap();
+        let pos_2025 = s.find("## 2025 Benchmarks").unwrap();
+        assert!(pos_2025 < pos_2024);
+        assert!(s.contains("[Day 1](./src/bin/2024_01.rs)"));
+        assert_eq!(s.matches("**Total: 190.00ms**").count(), 2);
+    }
 }
@@ -1,19 +1,50 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{HashMap, HashSet},
+    env, io,
+    path::Path,
+    process::{self, Command, ExitStatus},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, BenchConfig, Day, PuzzleId, Timeouts, Year,
+    is_counting_allocations, protocol::Event, runner::Solution,
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Runs the solutions of the selected days one after another.
+///
+/// Days found in `solutions` are run in-process, unless the run needs a different build profile
+/// or allocator than this binary, or may time out. Other days are run in their own binary.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    bench: Option<&BenchConfig>,
+    timeouts: &Timeouts,
+    alloc_stats: bool,
+    solutions: &[Solution],
+) -> Option<Timings> {
+    // NOTE: solutions that run in-process cannot be killed once they exceed a timeout.
+    let in_process = is_release != cfg!(debug_assertions)
+        && alloc_stats == is_counting_allocations()
+        && *timeouts == Timeouts::default();
+
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut failures: Vec<String> = vec![];
 
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    all_days(year)
         .filter(|day| days_to_run.contains(day))
         .for_each(|day| {
             if need_space {
@@ -24,17 +55,31 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            // NOTE: every day of the calendar is a valid puzzle.
+            let puzzle = PuzzleId::new(year, day).unwrap();
+            let solution = solutions
+                .iter()
+                .find(|solution| in_process && solution.puzzle == puzzle);
+
+            let run = match solution {
+                Some(solution) => Some(child_commands::SolutionRun {
+                    events: solution.run(bench),
+                    status: ExitStatus::default(),
+                    stdout: vec![],
+                    stderr: vec![],
+                }),
+                None => {
+                    child_commands::run_solution(puzzle, bench, is_release, timeouts, alloc_stats)
+                        .unwrap()
+                }
+            };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
+            timings.extend(report_run(day, run.as_ref(), &mut failures));
         });
 
-    if is_timed {
+    print_failures(&failures);
+
+    if bench.is_some() {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -46,10 +91,176 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// Hands the current command over to the [`child_commands::RUNNER_BIN`], which runs the solutions
+/// in-process, and exits with its exit code. Returns if the runner does not compile, so that the
+/// days can still run in their own binaries.
+pub fn run_in_runner(is_release: bool, alloc_stats: bool) {
+    match child_commands::build_runner(is_release, alloc_stats) {
+        Ok(Some(runner)) => {
+            let status = Command::new(runner)
+                .args(env::args_os().skip(1))
+                .status()
+                .unwrap();
+            process::exit(status.code().unwrap_or(1));
+        }
+        Ok(None) => eprintln!(
+            "{ANSI_ITALIC}Not all solutions compile, running every day in its own binary.{ANSI_RESET}\n"
+        ),
+        Err(e) => eprintln!("Failed to build the solutions runner: {e:?}\n"),
+    }
+}
+
+/// Runs the solutions of the selected days on `jobs` threads, each day in its own binary.
+///
+/// The output of a day is buffered while it runs and printed once all days before it are done,
+/// so that days appear in order. Solutions are built upfront with a single cargo invocation.
+pub fn run_parallel(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    timeouts: &Timeouts,
+    alloc_stats: bool,
+    jobs: usize,
+) {
+    // NOTE: every day of the calendar is a valid puzzle.
+    let puzzles: Vec<PuzzleId> = all_days(year)
+        .filter(|day| days_to_run.contains(day))
+        .map(|day| PuzzleId::new(year, day).unwrap())
+        .collect();
+
+    let scaffolded: Vec<PuzzleId> = puzzles
+        .iter()
+        .filter(|puzzle| Path::new(&get_path_for_bin(**puzzle)).exists())
+        .copied()
+        .collect();
+
+    // NOTE: without any bins to build, cargo would build every target of the package.
+    let (executables, build_status) = if scaffolded.is_empty() {
+        (HashMap::new(), ExitStatus::default())
+    } else {
+        child_commands::build_solutions(&scaffolded, is_release, alloc_stats).unwrap()
+    };
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let mut failures: Vec<String> = vec![];
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(puzzles.len()) {
+            let sender = sender.clone();
+            let (next, puzzles, scaffolded, executables) =
+                (&next, &puzzles, &scaffolded, &executables);
+
+            scope.spawn(move || {
+                while let Some(puzzle) = puzzles.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let run = match executables.get(puzzle) {
+                        Some(executable) => Some(
+                            child_commands::run_executable(executable, None, timeouts, true)
+                                .unwrap_or_else(|e| {
+                                    child_commands::SolutionRun::failed_to_start(executable, &e)
+                                }),
+                        ),
+                        None if scaffolded.contains(puzzle) => {
+                            Some(child_commands::SolutionRun::failed_build(build_status))
+                        }
+                        None => None,
+                    };
+
+                    if sender.send((*puzzle, run)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // NOTE: days finish in any order, hold back their output until it is their turn.
+        let mut finished = HashMap::new();
+        let mut printed = 0;
+
+        for (puzzle, run) in receiver {
+            finished.insert(puzzle, run);
+
+            for (puzzle, run) in take_in_order(&puzzles, &mut printed, &mut finished) {
+                let day = puzzle.day();
+                if puzzle != puzzles[0] {
+                    println!();
+                }
+
+                println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+                println!("------");
+
+                if let Some(run) = &run {
+                    run.print_output();
+                }
+                report_run(day, run.as_ref(), &mut failures);
+            }
+        }
+    });
+
+    print_failures(&failures);
+}
+
+/// Takes the finished items that are next in `order`, starting at the `printed` cursor, and
+/// advances the cursor past them. Items that finished before their turn stay in `finished`.
+fn take_in_order<T>(
+    order: &[PuzzleId],
+    printed: &mut usize,
+    finished: &mut HashMap<PuzzleId, T>,
+) -> Vec<(PuzzleId, T)> {
+    let mut ready = vec![];
+    while let Some(item) = order.get(*printed).and_then(|x| finished.remove(x)) {
+        ready.push((order[*printed], item));
+        *printed += 1;
+    }
+    ready
+}
+
+/// Collects the failures of a day once its solution ran, returning its timing if it was solved.
+fn report_run(
+    day: Day,
+    run: Option<&child_commands::SolutionRun>,
+    failures: &mut Vec<String>,
+) -> Option<Timing> {
+    let Some(run) = run else {
+        println!("Not solved.");
+        return None;
+    };
+
+    failures.extend(
+        child_commands::collect_failures(run)
+            .into_iter()
+            .map(|failure| format!("Day {day} {failure}")),
+    );
+
+    // NOTE: days without an input only report the failure to read it.
+    if run
+        .events
+        .iter()
+        .all(|event| matches!(event, Event::Error { step: None, .. }))
+    {
+        println!("Not solved.");
+        None
+    } else {
+        Some(child_commands::collect_timing(&run.events, day))
+    }
+}
+
+fn print_failures(failures: &[String]) {
+    if !failures.is_empty() {
+        println!("\n{ANSI_BOLD}Failures:{ANSI_RESET}");
+        for failure in failures {
+            println!("  ✖ {failure}");
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    /// The build of a solution did not report the path of its executable.
+    MissingExecutable,
     IO(io::Error),
 }
 
@@ -60,46 +271,125 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{}.rs", puzzle.bin_name())
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading their results.
 pub mod child_commands {
     use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use crate::template::{
+        BenchConfig, Day, Part, PuzzleId, Timeouts,
+        protocol::{self, ErrorKind, Event, Step},
+    };
     use std::{
+        collections::HashMap,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{Command, ExitStatus, Stdio},
+        str::FromStr,
+        sync::mpsc::{self, RecvTimeoutError},
         thread,
+        time::Instant,
     };
+    use tinyjson::JsonValue;
+
+    /// The outcome of running a solution bin.
+    pub struct SolutionRun {
+        pub events: Vec<Event>,
+        pub status: ExitStatus,
+        /// Regular output of the solution, only collected if it ran buffered.
+        pub stdout: Vec<String>,
+        pub stderr: Vec<String>,
+    }
+
+    impl SolutionRun {
+        /// A run of a solution that did not compile, described by the exit status of the build.
+        pub fn failed_build(status: ExitStatus) -> Self {
+            Self {
+                events: vec![],
+                status,
+                stdout: vec![],
+                stderr: vec![],
+            }
+        }
+
+        /// A run of a solution whose binary could not be started, reported as a failure of the day.
+        pub fn failed_to_start(executable: &Path, error: &super::Error) -> Self {
+            let message = format!("could not run \"{}\": {error:?}", executable.display());
+            Self {
+                events: vec![Event::Error {
+                    step: None,
+                    kind: ErrorKind::Failed,
+                    message,
+                }],
+                status: ExitStatus::default(),
+                stdout: vec![],
+                stderr: vec![],
+            }
+        }
+
+        /// Prints the output that was collected while the solution ran buffered.
+        pub fn print_output(&self) {
+            for line in &self.stdout {
+                println!("{line}");
+            }
+            for line in &self.stderr {
+                eprintln!("{line}");
+            }
+        }
+    }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day, benching it if a [`BenchConfig`] is passed.
+    /// Returns [`None`] if the day has not been scaffolded yet.
+    ///
+    /// The solution is killed once it exceeds one of the [`Timeouts`]. The timeout is then
+    /// reported as an [`Event::Error`] of the step that was running.
+    ///
+    /// With `alloc_stats`, the solution is built with the `alloc-stats` feature to report heap usage.
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        bench: Option<&BenchConfig>,
+        is_release: bool,
+        timeouts: &Timeouts,
+        alloc_stats: bool,
+    ) -> Result<Option<SolutionRun>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
+            return Ok(None);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // NOTE: build ahead of running, so that timeouts do not include the compile time and the
+        // runner can kill the solution itself rather than `cargo run`.
+        let (executables, status) = build_solutions(&[puzzle], is_release, alloc_stats)?;
 
-        if is_release {
-            args.push("--release");
+        match executables.get(&puzzle) {
+            Some(executable) => run_executable(executable, bench, timeouts, false).map(Some),
+            None => Ok(Some(SolutionRun::failed_build(status))),
         }
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+    /// Runs a built solution bin, see [`run_solution`]. With `buffered`, its regular output is
+    /// collected in the [`SolutionRun`] instead of being forwarded while it runs.
+    pub fn run_executable(
+        executable: &Path,
+        bench: Option<&BenchConfig>,
+        timeouts: &Timeouts,
+        buffered: bool,
+    ) -> Result<SolutionRun, Error> {
+        let mut args = vec![protocol::FLAG.to_string()];
+
+        if let Some(bench) = bench {
+            // mirror `--time` flag and bench options to child invocations.
+            args.push("--time".to_string());
+            args.extend(bench.to_args());
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward regular output to stdout/stderr while collecting protocol events.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -108,85 +398,294 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let stderr_thread = thread::spawn(move || {
+            let mut lines = vec![];
+            for line in stderr.lines() {
+                let line = line.unwrap();
+                if buffered {
+                    lines.push(line);
+                } else {
+                    eprintln!("{line}");
+                }
+            }
+            lines
+        });
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
+        // NOTE: read stdout on a separate thread, so that waiting for output can time out.
+        let (sender, receiver) = mpsc::channel();
+        let stdout_thread = thread::spawn(move || {
+            for line in stdout.lines() {
+                if sender.send(line.unwrap()).is_err() {
+                    break;
+                }
+            }
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let timer = Instant::now();
+        let mut running_step: Option<(Step, Instant)> = None;
+        let mut events = vec![];
+        let mut output = vec![];
+        let mut parse_errors = vec![];
+
+        let mut print = |line: String| {
+            if buffered {
+                output.push(line);
+            } else {
+                println!("{line}");
+            }
+        };
+
+        let mut handle_line = |line: String, running_step: &mut Option<(Step, Instant)>| {
+            match Event::from_line(&line) {
+                Some(Ok(event)) => {
+                    *running_step = match event {
+                        Event::Start { step } => Some((step, Instant::now())),
+                        _ => None,
+                    };
+                    events.push(event);
+                }
+                Some(Err(e)) => {
+                    let message = format!("Could not parse result from line: {line} ({e})");
+                    if buffered {
+                        parse_errors.push(message);
+                    } else {
+                        eprintln!("{message}");
+                    }
+                }
+                None => print(line),
+            }
+        };
+
+        let mut timeout: Option<Event> = None;
+
+        loop {
+            let step_elapsed = running_step.map(|(_, started)| started.elapsed());
+            let line = match timeouts.time_left(timer.elapsed(), step_elapsed) {
+                Some(time_left) => receiver.recv_timeout(time_left),
+                None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            match line {
+                Ok(line) => handle_line(line, &mut running_step),
+                Err(RecvTimeoutError::Disconnected) => break,
+                Err(RecvTimeoutError::Timeout) => {
+                    let step_elapsed = running_step.map(|(_, started)| started.elapsed());
+                    let Some(limit) = timeouts.exceeded(timer.elapsed(), step_elapsed) else {
+                        continue;
+                    };
+
+                    cmd.kill()?;
+
+                    timeout = Some(Event::Error {
+                        step: running_step.map(|(step, _)| step),
+                        kind: ErrorKind::Timeout,
+                        message: format!("timed out after {limit:.1?}"),
+                    });
+
+                    // forward output that was printed before the solution was killed.
+                    for line in receiver.iter() {
+                        handle_line(line, &mut running_step);
+                    }
+                    break;
+                }
+            }
+        }
+
+        stdout_thread.join().unwrap();
+        let mut stderr = stderr_thread.join().unwrap();
+        stderr.extend(parse_errors);
+        let status = cmd.wait()?;
+
+        if let Some(Event::Error { step, message, .. }) = &timeout {
+            let label = match step {
+                Some(Step::Parse) => "Parse".to_string(),
+                Some(Step::Part(part)) => format!("Part {part}"),
+                None => "Day".to_string(),
+            };
+            print(format!("{label}: ✖ {message}"));
         }
+        events.extend(timeout);
+
+        Ok(SolutionRun {
+            events,
+            status,
+            stdout: output,
+            stderr,
+        })
+    }
+
+    /// Name of the runner binary, which compiles in every solution with the `solutions` feature.
+    pub const RUNNER_BIN: &str = "solutions";
+
+    /// Builds the solution bins of the puzzles with a single cargo invocation, returning the paths
+    /// of their executables and the exit status of cargo. Solutions that do not compile are
+    /// missing from the executables, as cargo keeps going with the others.
+    pub fn build_solutions(
+        puzzles: &[PuzzleId],
+        is_release: bool,
+        alloc_stats: bool,
+    ) -> Result<(HashMap<PuzzleId, PathBuf>, ExitStatus), Error> {
+        let bins: Vec<String> = puzzles.iter().map(|x| x.bin_name()).collect();
+        let features: &[&str] = if alloc_stats { &["alloc-stats"] } else { &[] };
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let (mut executables, status) = cargo_build(&bins, is_release, features, Stdio::inherit())?;
+
+        let executables = puzzles
+            .iter()
+            .filter_map(|puzzle| Some((*puzzle, executables.remove(&puzzle.bin_name())?)))
+            .collect();
 
-        Ok(output)
+        Ok((executables, status))
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    /// Builds the [`RUNNER_BIN`] with the same build profile and allocator as a run, so that it
+    /// can run the solutions in-process. Returns [`None`] if it does not compile, e.g. because one
+    /// of the solutions has an error.
+    pub fn build_runner(is_release: bool, alloc_stats: bool) -> Result<Option<PathBuf>, Error> {
+        let features: &[&str] = if alloc_stats {
+            &["solutions", "alloc-stats"]
+        } else {
+            &["solutions"]
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        // NOTE: compile errors are reported once the broken solution is built on its own.
+        let (mut executables, _) = cargo_build(
+            &[RUNNER_BIN.to_string()],
+            is_release,
+            features,
+            Stdio::null(),
+        )?;
+
+        Ok(executables.remove(RUNNER_BIN))
+    }
+
+    /// Builds bins with a single cargo invocation, returning the paths of their executables by
+    /// name and the exit status of cargo.
+    fn cargo_build(
+        bins: &[String],
+        is_release: bool,
+        features: &[&str],
+        stderr: Stdio,
+    ) -> Result<(HashMap<String, PathBuf>, ExitStatus), Error> {
+        let mut args = vec![
+            "build".to_string(),
+            "--quiet".to_string(),
+            "--keep-going".to_string(),
+            "--message-format=json-render-diagnostics".to_string(),
+        ];
+
+        for bin in bins {
+            args.push("--bin".to_string());
+            args.push(bin.clone());
+        }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        if is_release {
+            args.push("--release".to_string());
+        }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+        if !features.is_empty() {
+            args.push("--features".to_string());
+            args.push(features.join(","));
+        }
+
+        let output = Command::new("cargo").args(&args).stderr(stderr).output()?;
+
+        // NOTE: cargo reports the path of the executable in the message of the built artifact.
+        let executables: HashMap<String, PathBuf> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| JsonValue::from_str(line).ok())
+            .filter_map(|json| {
+                let json = json.get::<HashMap<String, JsonValue>>()?;
+                let name = json
+                    .get("target")?
+                    .get::<HashMap<String, JsonValue>>()?
+                    .get("name")?
+                    .get::<String>()?;
+                let executable = json.get("executable")?.get::<String>()?;
+                bins.contains(name)
+                    .then(|| (name.clone(), PathBuf::from(executable)))
             })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            .collect();
 
-                timings.total_nanos += nanos;
-            });
+        if output.status.success() && executables.len() < bins.len() {
+            return Err(Error::MissingExecutable);
+        }
 
-        timings
+        Ok((executables, output.status))
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Describes the failed steps of a run, e.g. `Part 1: panicked at src/bin/2025_05.rs:4:60: oops`.
+    /// A run that exited with an error without reporting a failed step, e.g. because it did not
+    /// compile or aborted, is described by its exit status.
+    pub fn collect_failures(run: &SolutionRun) -> Vec<String> {
+        let failures: Vec<String> = run
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Error { step, message, .. } => Some(match step {
+                    Some(Step::Parse) => format!("Parse: {message}"),
+                    Some(Step::Part(part)) => format!("Part {part}: {message}"),
+                    None => message.clone(),
+                }),
+                Event::Start { .. } | Event::Result { .. } => None,
+            })
+            .collect();
+
+        if failures.is_empty() && !run.status.success() {
+            vec![format!("exited with {}", run.status)]
+        } else {
+            failures
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// Collects the timings of a day from the [`Event`]s emitted by its solution.
+    pub fn collect_timing(events: &[Event], day: Day) -> super::Timing {
+        let mut timing = super::Timing::new(day);
+
+        for event in events {
+            if let Event::Error {
+                kind: ErrorKind::Timeout,
+                ..
+            } = event
+            {
+                timing.timed_out = true;
+            }
+
+            let Event::Result {
+                step,
+                duration,
+                stats,
+                allocs,
+                ..
+            } = event
+            else {
+                continue;
+            };
+
+            let duration_str = Some(format!("{duration:.1?}"));
+
+            match step {
+                Step::Parse => {
+                    timing.parse = duration_str;
+                    timing.parse_stats.clone_from(stats);
+                    timing.parse_allocs = *allocs;
+                }
+                Step::Part(Part::One) => {
+                    timing.part_1 = duration_str;
+                    timing.part_1_stats.clone_from(stats);
+                    timing.part_1_allocs = *allocs;
+                }
+                Step::Part(Part::Two) => {
+                    timing.part_2 = duration_str;
+                    timing.part_2_stats.clone_from(stats);
+                    timing.part_2_allocs = *allocs;
+                }
+            }
+
+            timing.total_nanos += duration.as_nanos() as f64;
+        }
+
+        timing
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,53 +704,206 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::time::Duration;
+
+        use std::process::ExitStatus;
+
+        use super::{SolutionRun, collect_failures, collect_timing};
+
+        use crate::{
+            day,
+            template::{
+                BenchStats, Part, Timeouts,
+                protocol::{ErrorKind, Event, Step},
+            },
+        };
 
-        use crate::day;
+        fn result(step: Step, nanos: u64, stats: Option<BenchStats>) -> Event {
+            Event::Result {
+                step,
+                answer: Some("42".into()),
+                duration: Duration::from_nanos(nanos),
+                stats,
+                allocs: None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = collect_timing(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    result(Step::Part(Part::One), 74, None),
+                    result(Step::Part(Part::Two), 74_130_000, None),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_stats() {
+            let stats = BenchStats::from_samples(&[
+                Duration::from_millis(1),
+                Duration::from_millis(2),
+                Duration::from_millis(3),
+            ]);
+
+            let res = collect_timing(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    result(Step::Parse, 1_000_000, Some(stats.clone())),
+                    result(Step::Part(Part::One), 2_000_000, None),
+                    result(Step::Part(Part::Two), 3_000_000, Some(stats.clone())),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos, 6_000_000_f64);
+            assert_eq!(res.parse.unwrap(), "1.0ms");
+            assert_eq!(res.parse_stats, Some(stats.clone()));
+            assert_eq!(res.part_1_stats, None);
+            assert_eq!(res.part_2_stats, Some(stats));
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn collects_failures() {
+            let run = SolutionRun {
+                events: vec![
+                    Event::Error {
+                        step: Some(Step::Part(Part::One)),
+                        kind: ErrorKind::Panic,
+                        message: "panicked at src/bin/2025_01.rs:1:1: oops".into(),
+                    },
+                    result(Step::Part(Part::Two), 1, None),
+                ],
+                status: ExitStatus::default(),
+                stdout: vec![],
+                stderr: vec![],
+            };
+
+            assert_eq!(
+                collect_failures(&run),
+                vec!["Part 1: panicked at src/bin/2025_01.rs:1:1: oops"]
+            );
+        }
+
+        #[test]
+        #[cfg(unix)]
+        fn collects_failed_exits() {
+            use std::os::unix::process::ExitStatusExt;
+
+            let run = SolutionRun::failed_build(ExitStatus::from_raw(101 << 8));
+            assert_eq!(collect_failures(&run), vec!["exited with exit status: 101"]);
+
+            let run = SolutionRun {
+                events: vec![result(Step::Part(Part::One), 1, None)],
+                status: ExitStatus::default(),
+                stdout: vec![],
+                stderr: vec![],
+            };
+            assert!(collect_failures(&run).is_empty());
+        }
+
+        #[test]
+        #[cfg(unix)]
+        fn buffers_unparsable_events() {
+            use std::os::unix::fs::PermissionsExt;
+            use std::{env, fs, process};
+
+            let path = env::temp_dir().join(format!("aoc-unparsable-events-{}", process::id()));
+            fs::write(&path, "#!/bin/sh\necho '{\"aoc\": 1}'\necho done\n").unwrap();
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+            let run = super::run_executable(&path, None, &Timeouts::default(), true);
+            fs::remove_file(&path).unwrap();
+
+            let run = run.unwrap();
+            assert!(run.events.is_empty());
+            assert_eq!(run.stdout, ["done"]);
+            assert_eq!(run.stderr.len(), 1);
+            assert!(run.stderr[0].starts_with("Could not parse result from line: {\"aoc\": 1}"));
+        }
+
+        #[test]
+        fn collects_timeouts() {
+            let res = collect_timing(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    Event::Start {
+                        step: Step::Part(Part::One),
+                    },
+                    result(Step::Part(Part::One), 1_000, None),
+                    Event::Start {
+                        step: Step::Part(Part::Two),
+                    },
+                    Event::Error {
+                        step: Some(Step::Part(Part::Two)),
+                        kind: ErrorKind::Timeout,
+                        message: "timed out after 10.0s".into(),
+                    },
                 ],
                 day!(1),
             );
+            assert!(res.timed_out);
+            assert_eq!(res.part_1.unwrap(), "1.0µs");
+            assert!(res.part_2.is_none());
+
+            let res = collect_timing(&[result(Step::Part(Part::One), 1_000, None)], day!(1));
+            assert!(!res.timed_out);
+        }
+
+        #[test]
+        fn collects_missing_parts() {
+            let res = collect_timing(
+                &[Event::Error {
+                    step: Some(Step::Part(Part::One)),
+                    kind: ErrorKind::Panic,
+                    message: "failed".into(),
+                }],
+                day!(1),
+            );
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::take_in_order;
+    use crate::template::PuzzleId;
+    use crate::{day, year};
+
+    #[test]
+    fn takes_days_in_order() {
+        let order: Vec<PuzzleId> = [day!(1), day!(2), day!(3), day!(4)]
+            .into_iter()
+            .map(|day| PuzzleId::new(year!(2025), day).unwrap())
+            .collect();
+        let mut printed = 0;
+        let mut finished = HashMap::new();
+
+        finished.insert(order[2], "three");
+        assert!(take_in_order(&order, &mut printed, &mut finished).is_empty());
+
+        finished.insert(order[1], "two");
+        assert!(take_in_order(&order, &mut printed, &mut finished).is_empty());
+
+        finished.insert(order[0], "one");
+        assert_eq!(
+            take_in_order(&order, &mut printed, &mut finished),
+            [(order[0], "one"), (order[1], "two"), (order[2], "three")]
+        );
+        assert_eq!(printed, 3);
+        assert!(finished.is_empty());
+
+        finished.insert(order[3], "four");
+        assert_eq!(
+            take_in_order(&order, &mut printed, &mut finished),
+            [(order[3], "four")]
+        );
+        assert_eq!(printed, 4);
+    }
+}
@@ -1,101 +1,444 @@
 /// Encapsulates code that interacts with solution functions.
-use std::fmt::Display;
+use std::cell::{Cell, RefCell};
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::aoc_client::Backend;
+use crate::template::protocol::{self, ErrorKind, Event, Step};
+use crate::template::{
+    ANSI_ITALIC, ANSI_RESET, AllocStats, Answers, BenchConfig, BenchStats, Checksums, InputSource,
+    Outcome, Part, PuzzleId, Response, Submission, Submissions, ToOutcome, Verdict,
+    measure_allocations, part_two_description, store_examples, unix_now, wait_time,
+};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Installs the panic hook of [`catch_panic`] once per process.
+static INSTALL_PANIC_HOOK: Once = Once::new();
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+thread_local! {
+    /// Whether [`catch_panic`] is running on this thread.
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+    /// The message of the last panic caught on this thread.
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// A solution compiled into the runner binary with the `solutions` feature, so that `all` and
+/// `time` can call its parts in-process instead of spawning its binary.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    /// Runs the steps of the solution, generated by [`solution!`](crate::solution).
+    pub solve: fn(&mut Runner),
+}
+
+impl Solution {
+    /// Runs the solution against the puzzle input, returning the events of its steps.
+    pub fn run(&self, bench: Option<&BenchConfig>) -> Vec<Event> {
+        let mut runner = Runner::new(self.puzzle, bench.copied());
+        (self.solve)(&mut runner);
+        runner.events
+    }
+}
+
+/// Runs the steps of a solution, printing their results and collecting them as [`Event`]s.
+/// Events are also printed as protocol lines if the binary was invoked with [`protocol::FLAG`].
+pub struct Runner {
+    puzzle: PuzzleId,
+    input: InputSource,
+    bench: Option<BenchConfig>,
+    submit: Option<Part>,
+    events: Vec<Event>,
+}
+
+impl Runner {
+    /// Creates a runner for the puzzle input, benching every step if a [`BenchConfig`] is passed.
+    pub fn new(puzzle: PuzzleId, bench: Option<BenchConfig>) -> Self {
+        Self {
+            puzzle,
+            input: InputSource::Puzzle,
+            bench,
+            submit: None,
+            events: vec![],
+        }
+    }
+
+    /// Creates a runner from the arguments of a solution binary: the [`InputSource`] selected with
+    /// `--example`, `--example-n <n>` or `--input <path>`, `--time` with its bench options and
+    /// `--submit <part>`.
+    pub fn from_args(puzzle: PuzzleId) -> Self {
+        let mut args = pico_args::Arguments::from_env();
+
+        let input = InputSource::from_args(&mut args).unwrap_or_else(|e| {
+            exit_with_invalid_arguments(&format!("invalid input options: {e}"));
+        });
+
+        let bench = args.contains("--time").then(|| {
+            BenchConfig::from_args(&mut args).unwrap_or_else(|e| {
+                exit_with_invalid_arguments(&format!("invalid bench options: {e}"));
+            })
+        });
+
+        let submit = args.opt_value_from_str("--submit").unwrap_or_else(|_| {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        });
+
+        if submit.is_some() && input != InputSource::Puzzle {
+            eprintln!("Only results of the puzzle input can be submitted.");
+            process::exit(1);
+        }
+
+        Self {
+            puzzle,
+            input,
+            bench,
+            submit,
+            events: vec![],
+        }
+    }
+
+    /// Reads the input of the puzzle from the [`InputSource`] of the runner.
+    /// If the puzzle input cannot be read, this is reported as a failure and the steps are skipped.
+    pub fn read_input(&mut self) -> Option<String> {
+        match self.input.read(self.puzzle) {
+            Ok(input) if self.input == InputSource::Puzzle => Some(input),
+            Ok(input) => {
+                println!("{ANSI_ITALIC}Using {}.{ANSI_RESET}", self.input);
+                Some(input)
+            }
+            Err(e) if self.input == InputSource::Puzzle => {
+                let path = self.puzzle.data_path("inputs", "txt");
+                let message = format!("could not read \"{path}\": {e}");
+                self.report_failure(None, "Input", ErrorKind::Failed, message);
+                None
+            }
+            Err(e) => {
+                exit_with_invalid_arguments(&format!("could not read {}: {e}", self.input));
+            }
+        }
+    }
+
+    /// Runs a solution part and prints its result.
+    /// If the part panics or returns an error, it is reported as a failed part and the next part still runs.
+    pub fn run_part<I: Copy, R: ToOutcome>(&mut self, func: impl Fn(I) -> R, input: I, part: Part) {
+        let part_str = format!("Part {part}");
+
+        self.emit(Event::Start {
+            step: Step::Part(part),
+        });
+
+        let timed = catch_panic(|| {
+            run_timed(func, input, self.bench.as_ref(), |result| {
+                let outcome = result.outcome();
+                print_result(&outcome, &part_str, "");
+                outcome.is_finished()
+            })
+        });
+
+        let (result, duration, stats, allocs) = match timed {
+            Ok(timed) => timed,
+            Err(message) => {
+                self.report_failure(Some(Step::Part(part)), &part_str, ErrorKind::Panic, message);
+                return;
+            }
+        };
+
+        let outcome = result.outcome();
+
+        match &outcome {
+            Outcome::NotImplemented => {
+                print!("\r");
+                println!("{part_str}: {ANSI_ITALIC}not implemented{ANSI_RESET}");
+                return;
+            }
+            Outcome::Error(message) => {
+                self.report_failure(
+                    Some(Step::Part(part)),
+                    &part_str,
+                    ErrorKind::Failed,
+                    message.clone(),
+                );
+                return;
+            }
+            Outcome::Answer(_) | Outcome::NoAnswer => {}
+        }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+        let verdict = outcome.answer().map_or(Verdict::Unknown, |answer| {
+            self.input
+                .expected_answers(self.puzzle)
+                .verify(part, answer)
+        });
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+        print_result(
+            &outcome,
+            &part_str,
+            &format!(
+                "{}{}",
+                format_duration(&duration, stats.as_ref(), allocs.as_ref()),
+                format_verdict(&verdict)
+            ),
+        );
+
+        if let Some(stats) = &stats {
+            println!("  ↳ {stats}");
+        }
+
+        self.emit(Event::Result {
+            step: Step::Part(part),
+            answer: outcome.answer().map(ToString::to_string),
+            duration,
+            stats,
+            allocs,
+        });
+
+        if let Some(answer) = outcome.answer()
+            && self.submit == Some(part)
+        {
+            submit_result(answer, self.puzzle, part);
+        }
     }
+
+    /// Parses the input once, timing it like a solution part. The parsed input is shared by both parts.
+    /// If parsing panics, the parts are skipped.
+    pub fn run_parse<I: Copy, T>(&mut self, func: impl Fn(I) -> T, input: I) -> Option<T> {
+        self.emit(Event::Start { step: Step::Parse });
+
+        let timed = catch_panic(|| {
+            run_timed(func, input, self.bench.as_ref(), |_| {
+                print!("Parse:");
+                true
+            })
+        });
+
+        let (result, duration, stats, allocs) = match timed {
+            Ok(timed) => timed,
+            Err(message) => {
+                self.report_failure(Some(Step::Parse), "Parse", ErrorKind::Panic, message);
+                println!("Skipping parts, as the input could not be parsed.");
+                return None;
+            }
+        };
+
+        print!("\r");
+        println!(
+            "Parse:{}",
+            format_duration(&duration, stats.as_ref(), allocs.as_ref())
+        );
+
+        if let Some(stats) = &stats {
+            println!("  ↳ {stats}");
+        }
+
+        self.emit(Event::Result {
+            step: Step::Parse,
+            answer: None,
+            duration,
+            stats,
+            allocs,
+        });
+
+        Some(result)
+    }
+
+    /// Exits with a non-zero status code if a step of the solution failed.
+    pub fn finish(self) {
+        if self
+            .events
+            .iter()
+            .any(|event| matches!(event, Event::Error { .. }))
+        {
+            process::exit(1);
+        }
+    }
+
+    fn emit(&mut self, event: Event) {
+        protocol::emit(&event);
+        self.events.push(event);
+    }
+
+    fn report_failure(
+        &mut self,
+        step: Option<Step>,
+        label: &str,
+        kind: ErrorKind,
+        message: String,
+    ) {
+        print!("\r");
+        println!("{label}: ✖ {message}");
+
+        self.emit(Event::Error {
+            step,
+            kind,
+            message,
+        });
+    }
+}
+
+fn exit_with_invalid_arguments(message: &str) -> ! {
+    eprintln!("Error: {message}");
+    protocol::emit(&Event::Error {
+        step: None,
+        kind: ErrorKind::InvalidArguments,
+        message: message.to_string(),
+    });
+    process::exit(1);
+}
+
+/// Calls `func`, catching unwinding panics. Returns the panic message and location on panic.
+fn catch_panic<R>(func: impl FnOnce() -> R) -> Result<R, String> {
+    // NOTE: the hook is installed once, as swapping hooks per call races between threads.
+    // Panics outside of `catch_panic` are handed to the previous hook, which prints them.
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING_PANICS.try_with(Cell::get).unwrap_or(false) {
+                PANIC_MESSAGE.set(Some(panic_message(info)));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let catching = CATCHING_PANICS.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING_PANICS.set(catching);
+
+    result.map_err(|_| PANIC_MESSAGE.take().unwrap_or_else(|| "panicked".into()))
+}
+
+fn panic_message(info: &panic::PanicHookInfo) -> String {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| (*s).to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".into());
+
+    let location = info
+        .location()
+        .map(|location| format!(" at {location}"))
+        .unwrap_or_default();
+
+    format!("panicked{location}: {message}")
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. with a [`BenchConfig`], i.e. `--time`, the function is benched according to it
+///     (by default approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// The returned duration is the median of the samples when benched. Heap usage is measured on
+/// the first execution if the `alloc-stats` feature is enabled.
+///
+/// `hook` receives the result of the first execution and returns whether it is worth benching.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    bench_config: Option<&BenchConfig>,
+    hook: impl Fn(&T) -> bool,
+) -> (T, Duration, Option<BenchStats>, Option<AllocStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, allocs) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        measure_allocations(|| func(input))
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
+    let should_bench = hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
+    match bench_config.filter(|_| should_bench) {
+        Some(config) => {
+            let stats = bench(func, input, &base_time, config);
+            (result, stats.median, Some(stats), allocs)
+        }
+        None => (result, base_time, None, allocs),
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let timer = Instant::now();
+    let is_over_time = || {
+        config
+            .max_bench_time
+            .is_some_and(|max_bench_time| timer.elapsed() >= max_bench_time)
+    };
+
+    // NOTE: the first execution counts towards the warmup.
+    let mut warmup_time = *base_time;
+    for _ in 0..config.warmup {
+        if warmup_time >= config.bench_time / 10 || is_over_time() {
+            break;
+        }
+
+        let warmup_timer = Instant::now();
+        black_box(func(black_box(input)));
+        warmup_time += warmup_timer.elapsed();
+    }
+
+    let bench_iterations = config.sample_count(*base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
+        // always take at least one sample, even if the warmup exceeded the wall-clock limit.
+        if !timers.is_empty() && is_over_time() {
+            break;
+        }
+
         let timer = Instant::now();
         black_box(func(black_box(input)));
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(
+    duration: &Duration,
+    stats: Option<&BenchStats>,
+    allocs: Option<&AllocStats>,
+) -> String {
+    let duration = match stats {
+        Some(stats) => format!("{duration:.1?} @ {} samples", stats.samples),
+        None => format!("{duration:.1?}"),
+    };
+
+    match allocs {
+        Some(allocs) => format!(" ({duration}, {allocs})"),
+        None => format!(" ({duration})"),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Correct => " ✔".into(),
+        Verdict::Incorrect { expected } => format!(" ✘ (expected {expected})"),
+        Verdict::Unknown => String::new(),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(outcome: &Outcome, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+    match outcome {
+        Outcome::Answer(result) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -114,7 +457,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Outcome::NoAnswer => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,46 +465,223 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        // NOTE: reported once the part returned.
+        Outcome::Error(_) | Outcome::NotImplemented => {}
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Refreshes the puzzle description once part one is solved, printing the revealed description
+/// of part two and extracting its example.
+fn reveal_part_two(backend: &Backend, puzzle: PuzzleId) {
+    let description = match backend.refresh_description(puzzle) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("Failed to refresh the puzzle description: {e}");
+            return;
+        }
+    };
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    if let Some(part_two) = part_two_description(&description) {
+        println!();
+        println!("{}", part_two.trim_end());
+        println!();
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if let Err(e) = store_examples(puzzle) {
+        eprintln!("Failed to store the examples of the puzzle: {e}");
     }
+}
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+/// Try to submit one part of the solution, selected with `--submit <part>`, if:
+///  1. a session cookie is configured, or aoc-cli is installed for `AOC_BACKEND=aoc-cli`.
+///  2. the answer passes the checks of [`Submissions::check`] against previous submissions.
+fn submit_result(result: &str, puzzle: PuzzleId, part: Part) {
+    let backend = Backend::from_env_or_exit();
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    if let Err(refusal) = Submissions::read(puzzle).check(part, result, unix_now()) {
+        eprintln!("Not submitting {result}: {refusal}");
         process::exit(1);
+    }
+
+    println!("Submitting result...");
+    let timestamp = unix_now();
+    let text = match backend.submit(puzzle, part, result) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Failed to submit part {part}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let response = Response::from_text(&text);
+
+    let submission = Submission {
+        part,
+        answer: result.trim().to_string(),
+        timestamp,
+        response,
+        wait: wait_time(&text),
     };
 
-    if part_submit != part {
-        return None;
+    if let Err(e) = Submissions::record(puzzle, submission) {
+        eprintln!("Failed to log the submission of part {part}: {e}");
+    }
+
+    if response == Response::Correct && part == Part::One {
+        reveal_part_two(&backend, puzzle);
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+    if response == Response::Correct {
+        match Answers::record(puzzle, part, result).and_then(|()| Checksums::record_answers(puzzle))
+        {
+            Ok(()) => println!("🎄 Stored the answer to part {part}."),
+            Err(e) => eprintln!("Failed to store the answer to part {part}: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::sync::Barrier;
+    use std::thread;
+
+    use super::{Runner, Solution, catch_panic};
+    use crate::template::protocol::Event;
+    use crate::template::{Part, PuzzleId};
+    use crate::{day, year};
+
+    /// Summarizes the events of a run without their durations and messages.
+    fn summarize(events: &[Event]) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| match event {
+                Event::Start { step } => format!("start {step}"),
+                Event::Result { step, answer, .. } => format!("result {step} {answer:?}"),
+                Event::Error { step, kind, .. } => format!("error {step:?} {kind:?}"),
+            })
+            .collect()
+    }
+
+    fn run(solve: fn(&mut Runner)) -> Vec<Event> {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        Solution { puzzle, solve }.run(None)
+    }
+
+    fn message(event: &Event) -> &str {
+        match event {
+            Event::Error { message, .. } => message,
+            _ => panic!("expected an error, got {event:?}"),
+        }
+    }
+
+    #[test]
+    fn reports_results() {
+        let events = run(|runner| {
+            let Some(input) = runner.run_parse(|input: &str| input.len(), "12345") else {
+                return;
+            };
+            runner.run_part(|len: usize| Some(len * 2), input, Part::One);
+        });
+
+        assert_eq!(
+            summarize(&events),
+            [
+                "start parse",
+                "result parse None",
+                "start 1",
+                "result 1 Some(\"10\")"
+            ]
         );
-        process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn runs_next_part_after_panic() {
+        let events = run(|runner| {
+            runner.run_part(|_: &str| -> Option<u64> { panic!("oops") }, "", Part::One);
+            runner.run_part(|_: &str| Some(2), "", Part::Two);
+        });
+
+        assert_eq!(
+            summarize(&events),
+            [
+                "start 1",
+                "error Some(Part(One)) Panic",
+                "start 2",
+                "result 2 Some(\"2\")"
+            ]
+        );
+        assert!(message(&events[1]).ends_with(": oops"));
+    }
+
+    #[test]
+    fn reports_failed_parts() {
+        let events = run(|runner| {
+            runner.run_part(|_: &str| Err::<u64, _>("no answer"), "", Part::One);
+        });
+
+        assert_eq!(
+            summarize(&events),
+            ["start 1", "error Some(Part(One)) Failed"]
+        );
+        assert_eq!(message(&events[1]), "no answer");
+    }
+
+    #[test]
+    fn skips_parts_after_failed_parse() {
+        let events = run(|runner| {
+            let Some(input) = runner.run_parse(|_: &str| -> u64 { panic!("oops") }, "") else {
+                return;
+            };
+            runner.run_part(|input: u64| Some(input), input, Part::One);
+        });
+
+        assert_eq!(
+            summarize(&events),
+            ["start parse", "error Some(Parse) Panic"]
+        );
+    }
+
+    #[test]
+    fn reports_missing_input() {
+        let puzzle = PuzzleId::new(year!(2015), day!(25)).unwrap();
+        let events = Solution {
+            puzzle,
+            solve: |runner| {
+                let Some(input) = runner.read_input() else {
+                    return;
+                };
+                runner.run_part(|input: &str| Some(input.len()), input.as_str(), Part::One);
+            },
+        }
+        .run(None);
+
+        assert_eq!(summarize(&events), ["error None Failed"]);
+        assert!(message(&events[0]).contains("data/2015/inputs/25.txt"));
+    }
+
+    #[test]
+    fn catches_panics_on_concurrent_threads() {
+        let barrier = Barrier::new(2);
+
+        let messages: Vec<String> = thread::scope(|scope| {
+            let handles: Vec<_> = ["one", "two"]
+                .into_iter()
+                .map(|name| {
+                    let barrier = &barrier;
+                    scope.spawn(move || {
+                        catch_panic(|| {
+                            barrier.wait();
+                            panic!("{name}");
+                        })
+                        .unwrap_err()
+                    })
+                })
+                .collect();
+
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert!(messages[0].starts_with("panicked at") && messages[0].ends_with(": one"));
+        assert!(messages[1].starts_with("panicked at") && messages[1].ends_with(": two"));
+    }
 }
@@ -0,0 +1,208 @@
+/// Statistics over the samples collected while benching a solution part.
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::Duration;
+
+use tinyjson::JsonValue;
+
+/// Summary statistics of a set of benchmark samples.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    /// Number of samples outside of the Tukey fences (1.5 times the interquartile range).
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes the statistics of a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "expected at least one sample");
+
+        let mut nanos: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let len = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (len - 1.0)
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let iqr = q3 - q1;
+        let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        let outliers = nanos
+            .iter()
+            .filter(|x| **x < lower_fence || **x > upper_fence)
+            .count();
+
+        Self {
+            samples: nanos.len() as u128,
+            mean: from_nanos(mean),
+            median: from_nanos(percentile(&nanos, 50.0)),
+            min: from_nanos(nanos[0]),
+            max: from_nanos(nanos[nanos.len() - 1]),
+            stddev: from_nanos(variance.sqrt()),
+            p95: from_nanos(percentile(&nanos, 95.0)),
+            p99: from_nanos(percentile(&nanos, 99.0)),
+            outliers: outliers as u128,
+        }
+    }
+}
+
+/// Displays every statistic except the number of samples, e.g.
+/// `median 1.2ms, mean 1.3ms, min 1.1ms, max 2.0ms, σ 150.0µs, p95 1.6ms, p99 1.9ms, 2 outliers`.
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "median {:.1?}, mean {:.1?}, min {:.1?}, max {:.1?}, σ {:.1?}, p95 {:.1?}, p99 {:.1?}, {} outliers",
+            self.median,
+            self.mean,
+            self.min,
+            self.max,
+            self.stddev,
+            self.p95,
+            self.p99,
+            self.outliers
+        )
+    }
+}
+
+/// Percentile of sorted values, linearly interpolated between the closest ranks.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        for (key, duration) in [
+            ("mean", value.mean),
+            ("median", value.median),
+            ("min", value.min),
+            ("max", value.max),
+            ("stddev", value.stddev),
+            ("p95", value.p95),
+            ("p99", value.p99),
+        ] {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            mean: from_nanos(number("mean")?),
+            median: from_nanos(number("median")?),
+            min: from_nanos(number("min")?),
+            max: from_nanos(number("max")?),
+            stddev: from_nanos(number("stddev")?),
+            p95: from_nanos(number("p95")?),
+            p99: from_nanos(number("p99")?),
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::BenchStats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.max, Duration::from_nanos(5));
+        // sample standard deviation of 1..=5 is ~1.58.
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42]));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p99, Duration::from_nanos(42));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn median_is_robust_against_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 10, 11, 10, 12, 10, 10, 1000]));
+        assert_eq!(stats.median, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(1000));
+        assert_eq!(stats.outliers, 1);
+        assert!(stats.mean > Duration::from_nanos(100));
+    }
+
+    #[test]
+    fn interpolates_percentiles() {
+        let samples: Vec<u64> = (1..=101).collect();
+        let stats = BenchStats::from_samples(&nanos(&samples));
+        assert_eq!(stats.p95, Duration::from_nanos(96));
+        assert_eq!(stats.p99, Duration::from_nanos(100));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 10, 11, 10, 12, 10, 10, 1000]));
+        let json = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
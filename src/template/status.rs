@@ -0,0 +1,196 @@
+use std::{fs, path::Path};
+
+use crate::template::{
+    Answers, Day, Part, PuzzleId, Response, Submissions, run_multi::get_path_for_bin,
+    table::align_columns, timings::Timings,
+};
+
+/// The progress of a single day, as shown by the `status` command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayProgress {
+    pub day: Day,
+    pub scaffolded: bool,
+    pub has_input: bool,
+    pub has_example: bool,
+    /// Number of parts that return an answer. Only known if the solution was run.
+    pub answers: Option<usize>,
+    /// Number of parts with an accepted submission.
+    pub submitted: usize,
+    /// Number of solved parts, according to the puzzle description and the submissions.
+    pub stars: usize,
+    /// Total runtime of the benched parts in nanoseconds, and whether both parts were benched.
+    pub benched: Option<(f64, bool)>,
+}
+
+impl DayProgress {
+    /// Reads the progress of a day from the data directory.
+    pub fn read(puzzle: PuzzleId, timings: &Timings) -> Self {
+        let is_non_empty = |path: String| fs::metadata(path).is_ok_and(|x| x.len() > 0);
+
+        let submissions = Submissions::read(puzzle);
+        let accepted = |part: Part| {
+            submissions
+                .data
+                .iter()
+                .any(|x| x.part == part && x.response == Response::Correct)
+        };
+
+        let description = fs::read_to_string(puzzle.data_path("puzzles", "md")).unwrap_or_default();
+        let solved = Answers::from_description(&description);
+
+        let benched = timings
+            .data
+            .iter()
+            .find(|timing| timing.day == puzzle.day())
+            .map(|timing| (timing.total_nanos, timings.is_day_complete(puzzle.day())));
+
+        Self {
+            day: puzzle.day(),
+            scaffolded: Path::new(&get_path_for_bin(puzzle)).exists(),
+            has_input: is_non_empty(puzzle.data_path("inputs", "txt")),
+            has_example: is_non_empty(puzzle.data_path("examples", "txt")),
+            answers: None,
+            submitted: Part::ALL.into_iter().filter(|x| accepted(*x)).count(),
+            stars: Part::ALL
+                .into_iter()
+                .filter(|x| accepted(*x) || solved.get(*x).is_some())
+                .count(),
+            benched,
+        }
+    }
+}
+
+/// Renders the progress of days as an aligned table, followed by the totals.
+/// The answers column is only shown if the solutions were run.
+pub fn render_status(days: &[DayProgress]) -> Vec<String> {
+    let show_answers = days.iter().any(|x| x.answers.is_some());
+    let check = |x: bool| if x { "✔" } else { "-" }.to_string();
+    let parts = |x: usize| if x > 0 { format!("{x}/2") } else { "-".into() };
+
+    let mut header = vec!["Day", "Solution", "Input", "Example"];
+    if show_answers {
+        header.push("Answers");
+    }
+    header.extend(["Submitted", "Stars", "Benched"]);
+
+    let mut rows: Vec<Vec<String>> = vec![header.into_iter().map(String::from).collect()];
+
+    for progress in days {
+        let mut row = vec![
+            progress.day.to_string(),
+            check(progress.scaffolded),
+            check(progress.has_input),
+            check(progress.has_example),
+        ];
+        if show_answers {
+            row.push(progress.answers.map_or("-".into(), parts));
+        }
+        row.extend([
+            parts(progress.submitted),
+            "*".repeat(progress.stars),
+            match progress.benched {
+                Some((nanos, true)) => format!("{:.2}ms", nanos / 1_000_000_f64),
+                Some((nanos, false)) => format!("{:.2}ms (partial)", nanos / 1_000_000_f64),
+                None => "-".into(),
+            },
+        ]);
+        rows.push(row);
+    }
+
+    let mut lines = align_columns(&rows);
+
+    let stars: usize = days.iter().map(|x| x.stars).sum();
+    let total_nanos = days
+        .iter()
+        .filter_map(|x| x.benched)
+        .fold(0_f64, |total, x| total + x.0);
+
+    lines.push(String::new());
+    lines.push(format!(
+        "Stars: {stars}/{} · Total runtime: {:.2}ms",
+        days.len() * 2,
+        total_nanos / 1_000_000_f64
+    ));
+
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayProgress, render_status};
+    use crate::day;
+
+    fn progress() -> Vec<DayProgress> {
+        vec![
+            DayProgress {
+                day: day!(1),
+                scaffolded: true,
+                has_input: true,
+                has_example: true,
+                answers: None,
+                submitted: 2,
+                stars: 2,
+                benched: Some((1_500_000.0, true)),
+            },
+            DayProgress {
+                day: day!(2),
+                scaffolded: true,
+                has_input: false,
+                has_example: true,
+                answers: None,
+                submitted: 0,
+                stars: 1,
+                benched: Some((250_000.0, false)),
+            },
+            DayProgress {
+                day: day!(3),
+                scaffolded: false,
+                has_input: false,
+                has_example: false,
+                answers: None,
+                submitted: 0,
+                stars: 0,
+                benched: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn renders_status() {
+        assert_eq!(
+            render_status(&progress()),
+            vec![
+                "Day  Solution  Input  Example  Submitted  Stars  Benched",
+                "01   ✔         ✔      ✔        2/2        **     1.50ms",
+                "02   ✔         -      ✔        -          *      0.25ms (partial)",
+                "03   -         -      -        -                 -",
+                "",
+                "Stars: 3/6 · Total runtime: 1.75ms",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_answers_if_known() {
+        let mut days = progress();
+        days[0].answers = Some(2);
+
+        let lines = render_status(&days);
+        assert_eq!(
+            lines[0],
+            "Day  Solution  Input  Example  Answers  Submitted  Stars  Benched"
+        );
+        assert_eq!(
+            lines[1],
+            "01   ✔         ✔      ✔        2/2      2/2        **     1.50ms"
+        );
+        assert_eq!(
+            lines[2],
+            "02   ✔         -      ✔        -        -          *      0.25ms (partial)"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,495 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::Error,
+    path::Path,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Part, PuzzleId};
+
+/// How Advent of Code responded to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted too soon after a previous submission and was not checked.
+    TooRecent,
+    /// The response could not be recognized, e.g. because the part was solved already.
+    Unknown,
+}
+
+impl Response {
+    /// Recognizes the response in the text of the answer page.
+    pub fn from_text(text: &str) -> Self {
+        if text.contains("That's the right answer") {
+            Response::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Response::TooRecent
+        } else if text.contains("your answer is too high") {
+            Response::TooHigh
+        } else if text.contains("your answer is too low") {
+            Response::TooLow
+        } else if text.contains("That's not the right answer") {
+            Response::Wrong
+        } else {
+            Response::Unknown
+        }
+    }
+
+    /// Whether the submitted answer is known to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Response::Wrong | Response::TooHigh | Response::TooLow)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Response::Correct => "correct",
+            Response::Wrong => "wrong",
+            Response::TooHigh => "too_high",
+            Response::TooLow => "too_low",
+            Response::TooRecent => "too_recent",
+            Response::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.as_str().replace('_', " "))
+    }
+}
+
+impl FromStr for Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Response::Correct,
+            Response::Wrong,
+            Response::TooHigh,
+            Response::TooLow,
+            Response::TooRecent,
+            Response::Unknown,
+        ]
+        .into_iter()
+        .find(|response| response.as_str() == s)
+        .ok_or(format!("unknown response `{s}`."))
+    }
+}
+
+/// Extracts how long Advent of Code asks to wait before the next submission, e.g. from
+/// "Please wait one minute before trying again." or "You have 1m 23s left to wait."
+pub fn wait_time(text: &str) -> Option<Duration> {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let (_, time) = before.rsplit_once("You have ")?;
+        return time.split_whitespace().try_fold(Duration::ZERO, |acc, x| {
+            let (value, unit) = x.split_at(x.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            let secs = match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+            Some(acc + Duration::from_secs(secs))
+        });
+    }
+
+    let (_, after) = text.split_once("lease wait ")?;
+    let (amount, _) = after.split_once(" minute")?;
+    let minutes = match amount {
+        "one" | "a" => 1,
+        amount => amount.parse().ok()?,
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: Part,
+    pub answer: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub response: Response,
+    /// How long Advent of Code asked to wait before the next submission.
+    pub wait: Option<Duration>,
+}
+
+impl Submission {
+    /// Point in time (in seconds since the Unix epoch) until which no answer may be submitted.
+    fn wait_until(&self) -> Option<u64> {
+        self.wait.map(|wait| self.timestamp + wait.as_secs())
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect { answer: String },
+    AlreadyWrong { response: Response },
+    OutOfBounds { bound: String, response: Response },
+    MustWait { left: Duration },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect { answer } => {
+                write!(f, "the part was solved already, its answer is {answer}.")
+            }
+            Refusal::AlreadyWrong { response } => {
+                write!(f, "this answer was submitted before and is {response}.")
+            }
+            Refusal::OutOfBounds { bound, response } => {
+                write!(f, "{bound} was submitted before and is {response}.")
+            }
+            Refusal::MustWait { left } => {
+                write!(
+                    f,
+                    "please wait {}s before submitting again.",
+                    left.as_secs()
+                )
+            }
+        }
+    }
+}
+
+/// The log of submitted answers of a puzzle, stored in `data/<year>/submissions/DD.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+fn get_submissions_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("submissions", "json")
+}
+
+/// Current time in seconds since the Unix epoch.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs())
+}
+
+impl Submissions {
+    /// Rehydrate the submissions of a puzzle from its JSON file. If not present, returns an empty log.
+    pub fn read(puzzle: PuzzleId) -> Self {
+        fs::read_to_string(get_submissions_path(puzzle))
+            .map_err(|x| x.to_string())
+            .and_then(Submissions::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Dehydrate the submissions of a puzzle to its JSON file.
+    pub fn store(&self, puzzle: PuzzleId) -> Result<(), Error> {
+        let path = get_submissions_path(puzzle);
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Appends a submission to the log of a puzzle.
+    pub fn record(puzzle: PuzzleId, submission: Submission) -> Result<(), Error> {
+        let mut submissions = Self::read(puzzle);
+        submissions.data.push(submission);
+        submissions.store(puzzle)
+    }
+
+    /// Checks whether `answer` is worth submitting at `now` (in seconds since the Unix epoch):
+    /// it must not be known to be wrong, must lie within the bounds of previous "too high" and
+    /// "too low" responses, and the wait time of the last submission must have passed.
+    pub fn check(&self, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        let answer = answer.trim();
+        let of_part = || self.data.iter().filter(move |x| x.part == part);
+
+        if let Some(correct) = of_part().find(|x| x.response == Response::Correct) {
+            return Err(Refusal::AlreadyCorrect {
+                answer: correct.answer.clone(),
+            });
+        }
+
+        if let Some(wrong) = of_part().find(|x| x.answer == answer && x.response.is_wrong()) {
+            return Err(Refusal::AlreadyWrong {
+                response: wrong.response,
+            });
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let out_of_bounds = of_part().find(|x| {
+                x.answer
+                    .parse::<i128>()
+                    .is_ok_and(|bound| match x.response {
+                        Response::TooHigh => value >= bound,
+                        Response::TooLow => value <= bound,
+                        _ => false,
+                    })
+            });
+
+            if let Some(bound) = out_of_bounds {
+                return Err(Refusal::OutOfBounds {
+                    bound: bound.answer.clone(),
+                    response: bound.response,
+                });
+            }
+        }
+
+        // NOTE: Advent of Code throttles submissions of all parts alike.
+        let wait_until = self.data.iter().filter_map(Submission::wait_until).max();
+        if let Some(wait_until) = wait_until.filter(|x| *x > now) {
+            return Err(Refusal::MustWait {
+                left: Duration::from_secs(wait_until - now),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        let data = value
+            .data
+            .iter()
+            .map(|submission| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                map.insert(
+                    "part".into(),
+                    JsonValue::Number(submission.part.into_inner().into()),
+                );
+                map.insert(
+                    "answer".into(),
+                    JsonValue::String(submission.answer.clone()),
+                );
+                map.insert(
+                    "timestamp".into(),
+                    JsonValue::Number(submission.timestamp as f64),
+                );
+                map.insert(
+                    "response".into(),
+                    JsonValue::String(submission.response.as_str().into()),
+                );
+                if let Some(wait) = submission.wait {
+                    map.insert("wait".into(), JsonValue::Number(wait.as_secs() as f64));
+                }
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        JsonValue::Array(data)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected JSON document to be an array.")?;
+
+        let data = json
+            .iter()
+            .map(|value| {
+                let map = value
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("expected submission to be an object.")?;
+
+                let number = |key: &str| {
+                    map.get(key)
+                        .and_then(|v| v.get::<f64>())
+                        .copied()
+                        .ok_or(format!("expected `json.{key}` to be a number."))
+                };
+
+                let string = |key: &str| {
+                    map.get(key)
+                        .and_then(|v| v.get::<String>())
+                        .cloned()
+                        .ok_or(format!("expected `json.{key}` to be a string."))
+                };
+
+                Ok(Submission {
+                    part: Part::new(number("part")? as u8)
+                        .ok_or("expected `json.part` to be 1 or 2.")?,
+                    answer: string("answer")?,
+                    timestamp: number("timestamp")? as u64,
+                    response: string("response")?.parse()?,
+                    wait: map
+                        .contains_key("wait")
+                        .then(|| number("wait"))
+                        .transpose()?
+                        .map(|secs| Duration::from_secs(secs as u64)),
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Submissions { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Refusal, Response, Submission, Submissions, wait_time};
+    use crate::template::Part;
+
+    fn submission(part: Part, answer: &str, response: Response) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            timestamp: 1000,
+            response,
+            wait: Some(Duration::from_secs(60)),
+        }
+    }
+
+    #[test]
+    fn recognizes_responses() {
+        assert_eq!(
+            Response::from_text("That's the right answer! You are one gold star closer."),
+            Response::Correct
+        );
+        assert_eq!(
+            Response::from_text("That's not the right answer; your answer is too high."),
+            Response::TooHigh
+        );
+        assert_eq!(
+            Response::from_text("That's not the right answer; your answer is too low."),
+            Response::TooLow
+        );
+        assert_eq!(
+            Response::from_text("That's not the right answer. If you're stuck, ..."),
+            Response::Wrong
+        );
+        assert_eq!(
+            Response::from_text("You gave an answer too recently; you have to wait ..."),
+            Response::TooRecent
+        );
+        assert_eq!(
+            Response::from_text("You don't seem to be solving the right level."),
+            Response::Unknown
+        );
+    }
+
+    #[test]
+    fn extracts_wait_times() {
+        assert_eq!(
+            wait_time("Please wait one minute before trying again."),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            wait_time("please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            wait_time("You have 1m 23s left to wait."),
+            Some(Duration::from_secs(83))
+        );
+        assert_eq!(
+            wait_time("You have 42s left to wait."),
+            Some(Duration::from_secs(42))
+        );
+        assert_eq!(wait_time("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let submissions = Submissions {
+            data: vec![
+                submission(Part::One, "100", Response::TooHigh),
+                submission(Part::One, "10", Response::TooLow),
+                submission(Part::One, "abc", Response::Wrong),
+                submission(Part::Two, "7", Response::Correct),
+            ],
+        };
+        let later = 2000;
+
+        assert_eq!(submissions.check(Part::One, "50", later), Ok(()));
+        assert_eq!(
+            submissions.check(Part::One, "abc", later),
+            Err(Refusal::AlreadyWrong {
+                response: Response::Wrong
+            })
+        );
+        assert_eq!(
+            submissions.check(Part::One, "120", later),
+            Err(Refusal::OutOfBounds {
+                bound: "100".into(),
+                response: Response::TooHigh
+            })
+        );
+        assert_eq!(
+            submissions.check(Part::One, "100", later),
+            Err(Refusal::AlreadyWrong {
+                response: Response::TooHigh
+            })
+        );
+        assert_eq!(
+            submissions.check(Part::One, "5", later),
+            Err(Refusal::OutOfBounds {
+                bound: "10".into(),
+                response: Response::TooLow
+            })
+        );
+        assert_eq!(
+            submissions.check(Part::Two, "8", later),
+            Err(Refusal::AlreadyCorrect { answer: "7".into() })
+        );
+    }
+
+    #[test]
+    fn honors_wait_times() {
+        let submissions = Submissions {
+            data: vec![submission(Part::One, "100", Response::Wrong)],
+        };
+
+        assert_eq!(
+            submissions.check(Part::One, "50", 1020),
+            Err(Refusal::MustWait {
+                left: Duration::from_secs(40)
+            })
+        );
+        assert_eq!(submissions.check(Part::One, "50", 1060), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let submissions = Submissions {
+            data: vec![
+                submission(Part::One, "100", Response::TooHigh),
+                Submission {
+                    wait: None,
+                    ..submission(Part::Two, "7", Response::Correct)
+                },
+            ],
+        };
+        let json = JsonValue::from(&submissions).stringify().unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap(), submissions);
+        assert!(Submissions::try_from("{}".to_string()).is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::time::Duration;
+
+use crate::template::parse_duration;
+
+/// Limits how long a solution may run before the runner kills it.
+///
+/// `day` limits the whole run of a solution binary, `part` limits every single step, i.e. the
+/// parse step and each part. Benching counts towards both limits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    pub day: Option<Duration>,
+    pub part: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Parses the `--timeout` and `--part-timeout` flags. Without flags, solutions never time out.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            day: args.opt_value_from_fn("--timeout", parse_duration)?,
+            part: args.opt_value_from_fn("--part-timeout", parse_duration)?,
+        })
+    }
+
+    /// Time left until the day or the running step times out, [`None`] if nothing can time out.
+    /// `step_elapsed` is [`None`] while no step is running.
+    pub fn time_left(
+        &self,
+        day_elapsed: Duration,
+        step_elapsed: Option<Duration>,
+    ) -> Option<Duration> {
+        let day = self.day.map(|day| day.saturating_sub(day_elapsed));
+        let part = self
+            .part
+            .zip(step_elapsed)
+            .map(|(part, step_elapsed)| part.saturating_sub(step_elapsed));
+
+        match (day, part) {
+            (Some(day), Some(part)) => Some(day.min(part)),
+            (day, part) => day.or(part),
+        }
+    }
+
+    /// The limit that was exceeded, if any.
+    pub fn exceeded(
+        &self,
+        day_elapsed: Duration,
+        step_elapsed: Option<Duration>,
+    ) -> Option<Duration> {
+        let part = self
+            .part
+            .zip(step_elapsed)
+            .filter(|(part, step_elapsed)| step_elapsed >= part)
+            .map(|(part, _)| part);
+
+        part.or(self.day.filter(|day| day_elapsed >= *day))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{ffi::OsString, time::Duration};
+
+    use super::Timeouts;
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn parses_flags() {
+        let mut args = pico_args::Arguments::from_vec(vec![]);
+        assert_eq!(Timeouts::from_args(&mut args).unwrap(), Timeouts::default());
+
+        let mut args = pico_args::Arguments::from_vec(
+            ["--timeout", "1m", "--part-timeout", "10s"]
+                .iter()
+                .map(OsString::from)
+                .collect(),
+        );
+        assert_eq!(
+            Timeouts::from_args(&mut args).unwrap(),
+            Timeouts {
+                day: Some(secs(60)),
+                part: Some(secs(10)),
+            }
+        );
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--timeout".into(), "soon".into()]);
+        assert!(Timeouts::from_args(&mut args).is_err());
+    }
+
+    #[test]
+    fn computes_time_left() {
+        assert_eq!(
+            Timeouts::default().time_left(secs(100), Some(secs(100))),
+            None
+        );
+
+        let timeouts = Timeouts {
+            day: Some(secs(60)),
+            part: Some(secs(10)),
+        };
+        assert_eq!(timeouts.time_left(secs(20), None), Some(secs(40)));
+        assert_eq!(timeouts.time_left(secs(20), Some(secs(4))), Some(secs(6)));
+        assert_eq!(timeouts.time_left(secs(55), Some(secs(4))), Some(secs(5)));
+        assert_eq!(timeouts.time_left(secs(70), Some(secs(4))), Some(secs(0)));
+
+        let timeouts = Timeouts {
+            day: None,
+            part: Some(secs(10)),
+        };
+        assert_eq!(timeouts.time_left(secs(20), None), None);
+        assert_eq!(timeouts.time_left(secs(20), Some(secs(4))), Some(secs(6)));
+    }
+
+    #[test]
+    fn reports_exceeded_limit() {
+        let timeouts = Timeouts {
+            day: Some(secs(60)),
+            part: Some(secs(10)),
+        };
+        assert_eq!(timeouts.exceeded(secs(20), Some(secs(4))), None);
+        assert_eq!(timeouts.exceeded(secs(20), Some(secs(10))), Some(secs(10)));
+        assert_eq!(timeouts.exceeded(secs(60), Some(secs(4))), Some(secs(60)));
+        assert_eq!(timeouts.exceeded(secs(60), None), Some(secs(60)));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
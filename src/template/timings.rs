@@ -1,20 +1,62 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{AllocStats, BenchStats, Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+fn get_timings_path(year: Year) -> String {
+    format!("./data/{year}/timings.json")
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    /// Only present for solutions that parse their input with `solution!(day, parse = ...)`.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub parse_stats: Option<BenchStats>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Only present for solutions that were timed with the `alloc-stats` feature.
+    pub parse_allocs: Option<AllocStats>,
+    pub part_1_allocs: Option<AllocStats>,
+    pub part_2_allocs: Option<AllocStats>,
     pub total_nanos: f64,
+    /// Set when the solution was killed because it exceeded a timeout.
+    pub timed_out: bool,
+}
+
+impl Timing {
+    /// A timing of a day without any measurements.
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            parse_stats: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_allocs: None,
+            part_1_allocs: None,
+            part_2_allocs: None,
+            total_nanos: 0.0,
+            timed_out: false,
+        }
+    }
+
+    /// Heap usage of the whole day: the highest peak and the total number of allocations of its steps.
+    pub fn allocs(&self) -> Option<AllocStats> {
+        [&self.parse_allocs, &self.part_1_allocs, &self.part_2_allocs]
+            .into_iter()
+            .flatten()
+            .copied()
+            .reduce(|a, b| a.combine(&b))
+    }
 }
 
-/// Represents benchmark times for a set of days.
+/// Represents benchmark times for a set of days of a single year.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
@@ -22,16 +64,17 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings of a year to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        fs::create_dir_all(format!("./data/{year}"))?;
+        let mut file = fs::File::create(get_timings_path(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings of a year from a JSON file. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_timings_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +94,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,6 +103,24 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Rehydrate timings of every year that has a timings file, ordered by year.
+    pub fn read_all_years() -> Vec<(Year, Self)> {
+        let Ok(entries) = fs::read_dir("./data") else {
+            return vec![];
+        };
+
+        let mut years: Vec<Year> = entries
+            .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+            .filter(|year| fs::exists(get_timings_path(*year)).unwrap_or(false))
+            .collect();
+
+        years.sort_unstable();
+        years
+            .into_iter()
+            .map(|year| (year, Self::read_from_file(year)))
+            .collect()
+    }
+
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
@@ -133,6 +194,34 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::String(parse.clone()));
+        }
+
+        for (key, stats) in [
+            ("parse_stats", &value.parse_stats),
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+        ] {
+            if let Some(stats) = stats {
+                map.insert(key.into(), JsonValue::from(stats));
+            }
+        }
+
+        for (key, allocs) in [
+            ("parse_allocs", &value.parse_allocs),
+            ("part_1_allocs", &value.part_1_allocs),
+            ("part_2_allocs", &value.part_2_allocs),
+        ] {
+            if let Some(allocs) = allocs {
+                map.insert(key.into(), JsonValue::from(allocs));
+            }
+        }
+
+        if value.timed_out {
+            map.insert("timed_out".into(), JsonValue::Boolean(true));
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -161,16 +250,57 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // NOTE: parse times and stats are optional to support timings stored before they were collected.
+        let parse = json
+            .get("parse")
+            .map(|v| {
+                v.get::<String>()
+                    .cloned()
+                    .ok_or("Expected timing.parse to be a string.")
+            })
+            .transpose()?;
+
+        let parse_stats = json
+            .get("parse_stats")
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_1_stats = json
+            .get("part_1_stats")
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_2_stats = json
+            .get("part_2_stats")
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let allocs = |key: &str| json.get(key).map(AllocStats::try_from).transpose();
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        let timed_out = json
+            .get("timed_out")
+            .map_or(Some(&false), |v| v.get::<bool>())
+            .copied()
+            .ok_or("Expected timing.timed_out to be a boolean.")?;
+
         Ok(Timing {
             day,
+            parse,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            parse_stats,
+            part_1_stats,
+            part_2_stats,
+            parse_allocs: allocs("parse_allocs")?,
+            part_1_allocs: allocs("part_1_allocs")?,
+            part_2_allocs: allocs("part_2_allocs")?,
             total_nanos,
+            timed_out,
         })
     }
 }
@@ -187,29 +317,34 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{AllocStats, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
@@ -223,6 +358,61 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "samples": 10, "mean": 1100000, "median": 1000000, "min": 900000, "max": 2000000, "stddev": 300000, "p95": 1800000, "p99": 1960000, "outliers": 1 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.median, Duration::from_millis(1));
+            assert_eq!(stats.max, Duration::from_millis(2));
+            assert_eq!(stats.outliers, 1);
+            assert!(timing.part_2_stats.is_none());
+            assert!(timing.parse.is_none());
+            assert!(timing.parse_stats.is_none());
+        }
+
+        #[test]
+        fn handles_json_timings_with_parse() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "2ms", "part_1": "1ms", "part_2": null, "total_nanos": 3000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse, Some("2ms".to_string()));
+            assert_eq!(timing.part_1, Some("1ms".to_string()));
+        }
+
+        #[test]
+        fn handles_json_timings_with_timeout() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "timed_out": true }, { "day": "02", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert!(timings.data[0].timed_out);
+            assert!(!timings.data[1].timed_out);
+        }
+
+        #[test]
+        fn handles_json_timings_with_allocs() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000, "part_1_allocs": { "peak_bytes": 1024, "allocations": 2 }, "part_2_allocs": { "peak_bytes": 512, "allocations": 3 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert!(timing.parse_allocs.is_none());
+            assert_eq!(timing.part_1_allocs.unwrap().peak_bytes, 1024);
+            assert_eq!(
+                timing.allocs(),
+                Some(AllocStats {
+                    peak_bytes: 1024,
+                    allocations: 5,
+                })
+            );
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 0, "part_1_stats": { "samples": 10 } }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,7 +437,8 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
-        use std::collections::HashMap;
+        use crate::template::{BenchStats, timings::Timings};
+        use std::{collections::HashMap, time::Duration};
         use tinyjson::JsonValue;
 
         #[test]
@@ -266,6 +457,19 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn roundtrips_stats() {
+            let mut timings = get_mock_timings();
+            let stats =
+                BenchStats::from_samples(&[Duration::from_millis(9), Duration::from_millis(11)]);
+            timings.data[0].part_1_stats = Some(stats.clone());
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].part_1_stats, Some(stats));
+            assert_eq!(timings.data[0].part_2_stats, None);
+        }
     }
 
     mod is_day_complete {
@@ -278,42 +482,42 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -330,10 +534,10 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    ..Timing::new(day!(3))
                 }],
             };
             let merged = timings.merge(&other);
@@ -350,10 +554,10 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    ..Timing::new(day!(2))
                 }],
             };
             let merged = timings.merge(&other);
@@ -0,0 +1,235 @@
+/// Helpers to determine when puzzles unlock, based on an injectable [`Clock`].
+use std::{thread, time::Duration};
+
+use chrono::{DateTime, Datelike, FixedOffset, Utc};
+
+use crate::template::{Calendar, EVENT_MONTH, PuzzleId, SERVER_UTC_OFFSET, Year};
+
+/// A puzzle that unlocked less than this long ago is still considered "upcoming" by [`next_unlock`],
+/// so that `today --wait` does not skip a puzzle when started a few minutes late.
+pub const UNLOCK_GRACE_PERIOD: Duration = Duration::from_secs(60 * 60);
+
+/// A source of the current time, so that unlock logic can be tested without waiting.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The [`Clock`] backed by the system time.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+fn server_offset() -> FixedOffset {
+    // NOTE: the offset is well within the valid range of +-24 hours.
+    FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600).unwrap()
+}
+
+/// Returns the instant at which a puzzle unlocks.
+pub fn unlock_time(puzzle: PuzzleId) -> DateTime<Utc> {
+    let timestamp = Calendar::of(puzzle.year()).unlock_timestamp(puzzle.day());
+
+    // NOTE: unlock times of the supported years are well within the range of chrono.
+    DateTime::from_timestamp(i64::try_from(timestamp).unwrap(), 0).unwrap()
+}
+
+/// Returns the latest puzzle that has unlocked on the current server day, if an event is running.
+pub fn current_puzzle(clock: &impl Clock) -> Option<PuzzleId> {
+    let today = clock.now().with_timezone(&server_offset());
+    if today.month() != EVENT_MONTH {
+        return None;
+    }
+
+    let calendar = Calendar::of(Year::new(u16::try_from(today.year()).ok()?)?);
+    let day = calendar.day_of_december(u8::try_from(today.day()).ok()?)?;
+    PuzzleId::new(calendar.year(), day)
+}
+
+/// Returns the next puzzle to unlock, including puzzles that unlocked within the [`UNLOCK_GRACE_PERIOD`].
+pub fn next_unlock(clock: &impl Clock) -> Option<PuzzleId> {
+    let threshold = clock.now() - UNLOCK_GRACE_PERIOD;
+    let year = u16::try_from(threshold.with_timezone(&server_offset()).year()).ok()?;
+
+    [year, year + 1]
+        .into_iter()
+        .filter_map(Year::new)
+        .flat_map(|year| {
+            let calendar = Calendar::of(year);
+            calendar
+                .days()
+                .filter_map(move |day| PuzzleId::new(calendar.year(), day))
+        })
+        .find(|puzzle| unlock_time(*puzzle) > threshold)
+}
+
+/// Blocks until `instant` has passed, calling `on_tick` with the remaining time about once per second.
+pub fn wait_until(clock: &impl Clock, instant: DateTime<Utc>, mut on_tick: impl FnMut(Duration)) {
+    loop {
+        let Ok(remaining) = (instant - clock.now()).to_std() else {
+            return;
+        };
+
+        if remaining.is_zero() {
+            return;
+        }
+
+        on_tick(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+/// Calls `func` up to `attempts` times, sleeping for `delay` between failed attempts.
+pub fn retry<T, E>(
+    clock: &impl Clock,
+    attempts: usize,
+    delay: Duration,
+    mut func: impl FnMut(usize) -> Result<T, E>,
+) -> Result<T, E> {
+    let mut attempt = 1;
+    loop {
+        match func(attempt) {
+            Err(_) if attempt < attempts => {
+                clock.sleep(delay);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Formats a countdown as `HH:MM:SS`, prefixed by the number of days if needed.
+pub fn format_countdown(remaining: Duration) -> String {
+    // NOTE: round up so that the countdown reaches zero exactly when the puzzle unlocks.
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let (days, hours, minutes, seconds) =
+        (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use super::{
+        Clock, current_puzzle, format_countdown, next_unlock, retry, unlock_time, wait_until,
+    };
+    use crate::{
+        day,
+        template::{Day, PuzzleId, Year},
+        year,
+    };
+
+    struct MockClock(Cell<DateTime<Utc>>);
+
+    impl MockClock {
+        fn at(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> Self {
+            Self(Cell::new(
+                Utc.with_ymd_and_hms(year, month, day, hour, min, sec)
+                    .unwrap(),
+            ))
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0.set(self.0.get() + duration);
+        }
+    }
+
+    fn puzzle(year: u16, day: u8) -> PuzzleId {
+        PuzzleId::new(Year::new(year).unwrap(), Day::new(day).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        let unlock = unlock_time(PuzzleId::new(year!(2025), day!(3)).unwrap());
+        assert_eq!(unlock, Utc.with_ymd_and_hms(2025, 12, 3, 5, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn finds_current_puzzle() {
+        // 04:59 UTC is still the previous day in EST.
+        let clock = MockClock::at(2025, 12, 3, 4, 59, 0);
+        assert_eq!(current_puzzle(&clock), Some(puzzle(2025, 2)));
+        let clock = MockClock::at(2025, 12, 3, 5, 0, 0);
+        assert_eq!(current_puzzle(&clock), Some(puzzle(2025, 3)));
+        let clock = MockClock::at(2025, 12, 20, 12, 0, 0);
+        assert_eq!(current_puzzle(&clock), None);
+        let clock = MockClock::at(2025, 11, 20, 12, 0, 0);
+        assert_eq!(current_puzzle(&clock), None);
+    }
+
+    #[test]
+    fn finds_next_unlock() {
+        let clock = MockClock::at(2025, 11, 30, 12, 0, 0);
+        assert_eq!(next_unlock(&clock), Some(puzzle(2025, 1)));
+        let clock = MockClock::at(2025, 12, 3, 4, 58, 0);
+        assert_eq!(next_unlock(&clock), Some(puzzle(2025, 3)));
+        // within the grace period, the puzzle that just unlocked is still returned.
+        let clock = MockClock::at(2025, 12, 3, 5, 10, 0);
+        assert_eq!(next_unlock(&clock), Some(puzzle(2025, 3)));
+        let clock = MockClock::at(2025, 12, 3, 6, 10, 0);
+        assert_eq!(next_unlock(&clock), Some(puzzle(2025, 4)));
+        // after the last day of the event, the next event is targeted.
+        let clock = MockClock::at(2025, 12, 13, 12, 0, 0);
+        assert_eq!(next_unlock(&clock), Some(puzzle(2026, 1)));
+    }
+
+    #[test]
+    fn waits_until_unlock() {
+        let clock = MockClock::at(2025, 12, 3, 4, 59, 57);
+        let unlock = unlock_time(puzzle(2025, 3));
+        let mut ticks = vec![];
+        wait_until(&clock, unlock, |remaining| {
+            ticks.push(format_countdown(remaining))
+        });
+        assert_eq!(ticks, vec!["00:00:03", "00:00:02", "00:00:01"]);
+        assert_eq!(clock.now(), unlock);
+    }
+
+    #[test]
+    fn retries_failed_attempts() {
+        let clock = MockClock::at(2025, 12, 3, 5, 0, 0);
+        let result: Result<usize, ()> = retry(&clock, 3, Duration::from_secs(5), |attempt| {
+            if attempt < 3 { Err(()) } else { Ok(attempt) }
+        });
+        assert_eq!(result, Ok(3));
+        assert_eq!(
+            clock.now(),
+            unlock_time(puzzle(2025, 3)) + Duration::from_secs(10)
+        );
+
+        let result: Result<(), usize> = retry(&clock, 2, Duration::from_secs(5), Err);
+        assert_eq!(result, Err(2));
+    }
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(Duration::from_millis(500)), "00:00:01");
+        assert_eq!(format_countdown(Duration::from_secs(3723)), "01:02:03");
+        assert_eq!(format_countdown(Duration::from_secs(90000)), "1d 01:00:00");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,155 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The first year advent of code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent (i.e. an integer of 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR || year > 9999 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the year configured via the `AOC_YEAR` environment variable, if any.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Parses the year from a solution binary name such as `2025_01`.
+    pub const fn from_bin_name(name: &str) -> Option<Self> {
+        Self::from_bin_bytes(name.as_bytes())
+    }
+
+    /// Parses the year from the path of a solution source file such as `src/bin/2025_01.rs`.
+    pub const fn from_source_path(path: &str) -> Option<Self> {
+        let bytes = path.as_bytes();
+        let mut start = bytes.len();
+        while start > 0 && bytes[start - 1] != b'/' && bytes[start - 1] != b'\\' {
+            start -= 1;
+        }
+        Self::from_bin_bytes(bytes.split_at(start).1)
+    }
+
+    const fn from_bin_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 5 || bytes[4] != b'_' {
+            return None;
+        }
+
+        let mut year = 0;
+        let mut i = 0;
+        while i < 4 {
+            if !bytes[i].is_ascii_digit() {
+                return None;
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self::new(year)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value of 2015 or later")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_bin_names() {
+        assert_eq!(Year::from_bin_name("2025_01"), Some(Year(2025)));
+        assert_eq!(Year::from_bin_name("2015_25"), Some(Year(2015)));
+        assert_eq!(Year::from_bin_name("2014_01"), None);
+        assert_eq!(Year::from_bin_name("01"), None);
+        assert_eq!(Year::from_bin_name("20x5_01"), None);
+    }
+
+    #[test]
+    fn parses_source_paths() {
+        assert_eq!(
+            Year::from_source_path("src/bin/2025_01.rs"),
+            Some(Year(2025))
+        );
+        assert_eq!(
+            Year::from_source_path("C:\\aoc\\src\\bin\\2015_25.rs"),
+            Some(Year(2015))
+        );
+        assert_eq!(Year::from_source_path("2024_01.rs"), Some(Year(2024)));
+        assert_eq!(Year::from_source_path("src/bin/main.rs"), None);
+    }
+
+    #[test]
+    fn parses_strings() {
+        assert_eq!("2024".parse::<Year>().ok(), Some(Year(2024)));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */